@@ -0,0 +1,64 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use diakritikos::{pos, slot, solve, Diacritic, Position};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+enum BenchDiacritic {
+    Nasalized,
+    Lowered,
+    Voiceless,
+    Syllabic,
+    Labialized,
+    Centralized,
+}
+
+impl BenchDiacritic {
+    const ALL: [Self; 6] = [
+        Self::Nasalized,
+        Self::Lowered,
+        Self::Voiceless,
+        Self::Syllabic,
+        Self::Labialized,
+        Self::Centralized,
+    ];
+}
+
+impl Diacritic for BenchDiacritic {
+    fn renderings(&self) -> pos::PartialMap<&str> {
+        match self {
+            Self::Nasalized => {
+                pos::PartialMap::from_iter([(Position::Top, "\u{0303}")])
+            },
+            Self::Lowered => pos::PartialMap::from_iter([
+                (Position::Bottom, "\u{031e}"),
+                (Position::Right, "\u{02d5}"),
+            ]),
+            Self::Voiceless => pos::PartialMap::from_iter([
+                (Position::Bottom, "\u{0325}"),
+                (Position::Top, "\u{030a}"),
+            ]),
+            Self::Syllabic => pos::PartialMap::from_iter([
+                (Position::Bottom, "\u{0329}"),
+                (Position::Top, "\u{030d}"),
+            ]),
+            Self::Labialized => {
+                pos::PartialMap::from_iter([(Position::Right, "ʷ")])
+            },
+            Self::Centralized => {
+                pos::PartialMap::from_iter([(Position::Top, "\u{0308}")])
+            },
+        }
+    }
+}
+
+fn stacked_diacritics(criterion: &mut Criterion) {
+    let hints = slot::hints('ŋ').unwrap();
+    for count in 1 ..= BenchDiacritic::ALL.len() {
+        let diacritics = &BenchDiacritic::ALL[.. count];
+        criterion.bench_function(&format!("solve {} diacritics", count), |b| {
+            b.iter(|| solve(black_box(hints), black_box(diacritics.to_vec())))
+        });
+    }
+}
+
+criterion_group!(benches, stacked_diacritics);
+criterion_main!(benches);
@@ -0,0 +1,215 @@
+use crate::{
+    pos,
+    slot::{
+        metrics::{Metrics, Table},
+        Hint,
+    },
+};
+use std::fmt;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Tie {
+    Above,
+    Below,
+}
+
+impl Tie {
+    pub fn character(self) -> char {
+        match self {
+            Self::Above => '\u{0361}',
+            Self::Below => '\u{035c}',
+        }
+    }
+
+    fn metrics(self) -> Metrics {
+        match self {
+            Self::Above => Metrics { ascender: true, ..Metrics::default() },
+            Self::Below => Metrics { descender: true, ..Metrics::default() },
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub enum Attachment {
+    #[default]
+    Whole,
+    Member(usize),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Base {
+    pub characters: Vec<char>,
+    pub tie: Option<Tie>,
+}
+
+impl From<char> for Base {
+    fn from(character: char) -> Self {
+        Self::single(character)
+    }
+}
+
+impl Base {
+    pub fn single(character: char) -> Self {
+        Self { characters: vec![character], tie: None }
+    }
+
+    pub fn sequence<I>(characters: I) -> Self
+    where
+        I: IntoIterator<Item = char>,
+    {
+        Self { characters: characters.into_iter().collect(), tie: None }
+    }
+
+    pub fn tied<I>(characters: I, tie: Tie) -> Self
+    where
+        I: IntoIterator<Item = char>,
+    {
+        Self { characters: characters.into_iter().collect(), tie: Some(tie) }
+    }
+
+    pub fn len(&self) -> usize {
+        self.characters.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.characters.is_empty()
+    }
+
+    pub fn metrics(&self, table: &Table) -> Option<Metrics> {
+        let first = table.get(*self.characters.first()?)?;
+        let last = table.get(*self.characters.last()?)?;
+        let mut metrics = Metrics {
+            left: first.left,
+            right: last.right,
+            ..Metrics::default()
+        };
+        for &character in &self.characters {
+            let member = table.get(character)?;
+            metrics = metrics.union(Metrics {
+                ascender: member.ascender,
+                descender: member.descender,
+                ..Metrics::default()
+            });
+        }
+        Some(metrics.union(self.tie_metrics()))
+    }
+
+    pub fn member_metrics(
+        &self,
+        index: usize,
+        table: &Table,
+    ) -> Option<Metrics> {
+        let metrics = table.get(*self.characters.get(index)?)?;
+        Some(metrics.union(self.tie_metrics()))
+    }
+
+    pub fn hints(&self, table: &Table) -> Option<pos::TotalMap<Hint>> {
+        let metrics = self.metrics(table)?;
+        if self.len() > 1 {
+            Some(metrics.spanning_hints())
+        } else {
+            Some(metrics.hints())
+        }
+    }
+
+    pub fn member_hints(
+        &self,
+        index: usize,
+        table: &Table,
+    ) -> Option<pos::TotalMap<Hint>> {
+        self.member_metrics(index, table).map(Metrics::hints)
+    }
+
+    fn tie_metrics(&self) -> Metrics {
+        match self.tie {
+            Some(tie) if self.len() > 1 => tie.metrics(),
+            _ => Metrics::default(),
+        }
+    }
+}
+
+impl fmt::Display for Base {
+    fn fmt(&self, fmtr: &mut fmt::Formatter) -> fmt::Result {
+        for (index, character) in self.characters.iter().enumerate() {
+            if index > 0 {
+                if let Some(tie) = self.tie {
+                    write!(fmtr, "{}", tie.character())?;
+                }
+            }
+            write!(fmtr, "{}", character)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{Base, Tie};
+    use crate::slot::{
+        metrics::{Metrics, Table},
+        Hint,
+    };
+
+    #[test]
+    fn display_tied() {
+        assert_eq!(
+            Base::tied(['t', 'ʃ'], Tie::Above).to_string(),
+            "t\u{0361}ʃ"
+        );
+        assert_eq!(
+            Base::tied(['k', 'p'], Tie::Below).to_string(),
+            "k\u{035c}p"
+        );
+        assert_eq!(Base::sequence(['m', 'b']).to_string(), "mb");
+    }
+
+    #[test]
+    fn combined_metrics() {
+        let table = Table::builtin();
+        assert_eq!(
+            Base::tied(['a', 'e'], Tie::Below).metrics(table),
+            Some(Metrics { descender: true, ..Metrics::default() })
+        );
+        assert_eq!(
+            Base::sequence(['a', 'ɖ']).metrics(table),
+            Some(Metrics {
+                ascender: true,
+                descender: true,
+                right: true,
+                ..Metrics::default()
+            })
+        );
+        assert_eq!(
+            Base::sequence(['ɖ', 'a']).metrics(table),
+            Some(Metrics {
+                ascender: true,
+                descender: true,
+                ..Metrics::default()
+            })
+        );
+        assert_eq!(Base::sequence(['a', '\u{e000}']).metrics(table), None);
+        assert_eq!(
+            Base::tied(['a', 'e'], Tie::Below).hints(table),
+            Some(
+                Metrics { descender: true, ..Metrics::default() }
+                    .spanning_hints()
+            )
+        );
+        let hints = Base::single('n').hints(table).unwrap();
+        assert_eq!(hints.double_top, Hint::Excluded);
+        assert_eq!(hints.double_bottom, Hint::Excluded);
+    }
+
+    #[test]
+    fn member_hints() {
+        let table = Table::builtin();
+        let base = Base::tied(['n', 'd'], Tie::Above);
+        assert_eq!(
+            base.member_metrics(0, table),
+            Some(Metrics { ascender: true, ..Metrics::default() })
+        );
+        assert_eq!(base.member_hints(0, table).unwrap().top, Hint::Obstructed);
+        assert_eq!(base.member_hints(2, table), None);
+        assert_eq!(Base::single('n').member_hints(0, table), table.hints('n'));
+    }
+}
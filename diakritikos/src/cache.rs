@@ -0,0 +1,180 @@
+use crate::{
+    arrange,
+    cost,
+    pos,
+    slot,
+    CostModel,
+    Diacritic,
+    GraphemeCluster,
+    Position,
+    Slot,
+    SolveError,
+    Solver,
+};
+use std::{
+    collections::HashMap,
+    hash::Hash,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        RwLock,
+    },
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Statistics {
+    pub hits: u64,
+    pub misses: u64,
+}
+
+type Key<D> = (pos::TotalMap<slot::Hint>, Vec<D>);
+
+type Entry = Result<Vec<Position>, SolveError>;
+
+#[derive(Debug)]
+pub struct SolverCache<D, C = cost::Weights>
+where
+    D: Diacritic,
+    C: CostModel,
+{
+    cost_model: C,
+    entries: RwLock<HashMap<Key<D>, Entry>>,
+    hits: AtomicU64,
+    misses: AtomicU64,
+}
+
+impl<D, C> Default for SolverCache<D, C>
+where
+    D: Diacritic,
+    C: CostModel + Default,
+{
+    fn default() -> Self {
+        Self::with_cost_model(C::default())
+    }
+}
+
+impl<D> SolverCache<D>
+where
+    D: Diacritic,
+{
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl<D, C> SolverCache<D, C>
+where
+    D: Diacritic,
+    C: CostModel,
+{
+    pub fn with_cost_model(cost_model: C) -> Self {
+        Self {
+            cost_model,
+            entries: RwLock::new(HashMap::new()),
+            hits: AtomicU64::new(0),
+            misses: AtomicU64::new(0),
+        }
+    }
+
+    pub fn cost_model(&self) -> &C {
+        &self.cost_model
+    }
+
+    pub fn statistics(&self) -> Statistics {
+        Statistics {
+            hits: self.hits.load(Ordering::Relaxed),
+            misses: self.misses.load(Ordering::Relaxed),
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.read().unwrap().len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn clear(&self) {
+        self.entries.write().unwrap().clear();
+        self.hits.store(0, Ordering::Relaxed);
+        self.misses.store(0, Ordering::Relaxed);
+    }
+}
+
+impl<D, C> SolverCache<D, C>
+where
+    D: Diacritic + Clone + Eq + Hash,
+    C: CostModel,
+{
+    pub fn solve<I>(
+        &self,
+        character: char,
+        hints: pos::TotalMap<slot::Hint>,
+        diacritics: I,
+    ) -> Result<GraphemeCluster<D>, SolveError>
+    where
+        I: IntoIterator<Item = D>,
+    {
+        self.solve_slots(hints, diacritics)
+            .map(|slots| GraphemeCluster { character, slots })
+    }
+
+    pub fn solve_slots<I>(
+        &self,
+        hints: pos::TotalMap<slot::Hint>,
+        diacritics: I,
+    ) -> Result<pos::TotalMap<Slot<D>>, SolveError>
+    where
+        I: IntoIterator<Item = D>,
+    {
+        let key = (hints, diacritics.into_iter().collect::<Vec<D>>());
+
+        let cached = self.entries.read().unwrap().get(&key).cloned();
+        let positions = match cached {
+            Some(positions) => {
+                self.hits.fetch_add(1, Ordering::Relaxed);
+                positions
+            },
+            None => {
+                self.misses.fetch_add(1, Ordering::Relaxed);
+                let positions = self.find_positions(hints, key.1.clone());
+                self.entries
+                    .write()
+                    .unwrap()
+                    .insert(key.clone(), positions.clone());
+                positions
+            },
+        }?;
+
+        let mut slot_indices = pos::TotalMap::<Vec<usize>>::default();
+        for (index, position) in positions.into_iter().enumerate() {
+            slot_indices[position].push(index);
+        }
+        let (_, diacritics) = key;
+        arrange(&mut slot_indices, &diacritics);
+        let mut diacritics: Vec<_> = diacritics.into_iter().map(Some).collect();
+        Ok(slot_indices.map(|indices| Slot {
+            diacritics: indices
+                .into_iter()
+                .map(|index| diacritics[index].take().unwrap())
+                .collect(),
+        }))
+    }
+
+    fn find_positions(
+        &self,
+        hints: pos::TotalMap<slot::Hint>,
+        diacritics: Vec<D>,
+    ) -> Entry {
+        let len = diacritics.len();
+        let solution =
+            Solver::new(hints, diacritics, &self.cost_model).find_solution()?;
+        let mut positions = vec![Position::Top; len];
+        for (position, indices) in &solution.slot_indices {
+            for index in indices {
+                positions[*index] = position;
+            }
+        }
+        Ok(positions)
+    }
+}
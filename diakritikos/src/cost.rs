@@ -0,0 +1,82 @@
+use crate::{
+    pos::{self, Position},
+    slot,
+};
+
+pub trait CostModel {
+    fn rendering(&self, position: Position, preference: u8) -> u128;
+
+    fn inversion(&self, position: Position) -> u128;
+
+    fn obstruction(
+        &self,
+        position: Position,
+        hint: slot::Hint,
+        len: usize,
+    ) -> u128;
+
+    fn balance(&self, position: Position, len: usize, mean: usize) -> u128;
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Weights {
+    pub rendering: u128,
+    pub inversion: u128,
+    pub obstruction: u128,
+    pub balance: u128,
+    pub placement: pos::TotalMap<u128>,
+}
+
+impl Default for Weights {
+    fn default() -> Self {
+        Self {
+            rendering: 2,
+            inversion: 1,
+            obstruction: 1,
+            balance: 4,
+            placement: pos::TotalMap::default(),
+        }
+    }
+}
+
+impl CostModel for Weights {
+    fn rendering(&self, position: Position, preference: u8) -> u128 {
+        self.placement[position] + u128::from(preference) * self.rendering
+    }
+
+    fn inversion(&self, _position: Position) -> u128 {
+        self.inversion
+    }
+
+    fn obstruction(
+        &self,
+        _position: Position,
+        hint: slot::Hint,
+        len: usize,
+    ) -> u128 {
+        match hint {
+            slot::Hint::Regular | slot::Hint::Excluded => 0,
+            slot::Hint::Obstructed => {
+                u128::try_from(len).unwrap() * self.obstruction
+            },
+        }
+    }
+
+    fn balance(&self, _position: Position, len: usize, mean: usize) -> u128 {
+        u128::try_from(len.abs_diff(mean)).unwrap() * self.balance
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Score {
+    pub rendering: u128,
+    pub inversion: u128,
+    pub obstruction: u128,
+    pub balance: u128,
+}
+
+impl Score {
+    pub fn total(&self) -> u128 {
+        self.rendering + self.inversion + self.obstruction + self.balance
+    }
+}
@@ -0,0 +1,19 @@
+use crate::{cost, pos, Diacritic, Slot};
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Candidate<D>
+where
+    D: Diacritic,
+{
+    pub slots: pos::TotalMap<Slot<D>>,
+    pub score: cost::Score,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Explanation<D>
+where
+    D: Diacritic,
+{
+    pub chosen: Candidate<D>,
+    pub runners_up: Vec<Candidate<D>>,
+}
@@ -0,0 +1,137 @@
+use crate::{parse::Parser, pos, Diacritic, Position};
+use unicode_normalization::char::canonical_combining_class;
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Definition {
+    pub name: String,
+    pub renderings: pos::PartialMap<String>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub stacking_class: Option<u8>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub capacity: Option<usize>,
+}
+
+impl Diacritic for Definition {
+    fn renderings(&self) -> pos::PartialMap<&str> {
+        self.renderings
+            .iter()
+            .map(|(position, rendering)| (position, rendering.as_str()))
+            .collect()
+    }
+
+    fn capacity(&self, _position: Position) -> Option<usize> {
+        self.capacity
+    }
+
+    fn stacking_class(&self, position: Position) -> u8 {
+        match self.stacking_class {
+            Some(class) => class,
+            None => self
+                .renderings
+                .data(position)
+                .and_then(|rendering| rendering.chars().next())
+                .map_or(0, canonical_combining_class),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Inventory {
+    pub diacritics: Vec<Definition>,
+}
+
+impl Inventory {
+    pub fn get(&self, name: &str) -> Option<&Definition> {
+        self.diacritics.iter().find(|definition| definition.name == name)
+    }
+
+    pub fn parser(&self) -> Parser<Definition> {
+        Parser::new(self.diacritics.iter().cloned())
+    }
+}
+
+#[cfg(all(test, feature = "serde"))]
+mod test {
+    use super::{Definition, Inventory};
+    use crate::{pos, GraphemeCluster, Position};
+
+    const INVENTORY: &str = r#"
+        [[diacritics]]
+        name = "nasalized"
+        renderings = { top = "\u0303" }
+
+        [[diacritics]]
+        name = "voiceless"
+        renderings = { bottom = "\u0325", top = "\u030a" }
+
+        [[diacritics]]
+        name = "labialized"
+        renderings = { right = "ʷ" }
+        capacity = 1
+    "#;
+
+    #[test]
+    fn load_toml() {
+        let inventory: Inventory = toml::from_str(INVENTORY).unwrap();
+        let voiceless = inventory.get("voiceless").unwrap();
+        assert_eq!(
+            voiceless.renderings,
+            pos::PartialMap::from_iter([
+                (Position::Bottom, String::from("\u{0325}")),
+                (Position::Top, String::from("\u{030a}")),
+            ])
+        );
+        assert_eq!(inventory.get("labialized").unwrap().capacity, Some(1));
+        assert_eq!(inventory.get("lowered"), None);
+
+        let cluster = GraphemeCluster::solve(
+            'm',
+            pos::TotalMap::default(),
+            ["nasalized", "voiceless", "labialized"]
+                .map(|name| inventory.get(name).unwrap().clone()),
+        )
+        .unwrap();
+        assert_eq!(cluster.to_string(), "m\u{0303}\u{0325}ʷ");
+        assert_eq!(
+            inventory.parser().parse_cluster("m\u{0303}\u{0325}ʷ"),
+            Ok(cluster)
+        );
+    }
+
+    #[test]
+    fn json_round_trip() {
+        let definition = Definition {
+            name: String::from("lowered"),
+            renderings: pos::PartialMap::from_iter([
+                (Position::Bottom, String::from("\u{031e}")),
+                (Position::Left, String::from("\u{02d5}")),
+            ]),
+            stacking_class: Some(220),
+            capacity: None,
+        };
+        let json = serde_json::to_string(&definition).unwrap();
+        assert_eq!(
+            serde_json::from_str::<serde_json::Value>(&json).unwrap(),
+            serde_json::json!({
+                "name": "lowered",
+                "renderings": { "bottom": "\u{031e}", "left": "\u{02d5}" },
+                "stacking_class": 220,
+                "capacity": null,
+            })
+        );
+        assert_eq!(
+            serde_json::from_str::<Definition>(&json).unwrap(),
+            definition
+        );
+    }
+
+    #[test]
+    fn duplicated_position() {
+        let result = serde_json::from_str::<pos::PartialMap<String>>(
+            r#"{"top":"a","top":"b"}"#,
+        );
+        assert!(result.is_err());
+    }
+}
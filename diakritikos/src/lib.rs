@@ -1,12 +1,33 @@
 pub mod pos;
+pub mod base;
 pub mod slot;
+pub mod cost;
+pub mod explain;
+pub mod normal;
+pub mod parse;
+pub mod inventory;
+pub mod cache;
+pub mod svg;
 
+pub use cost::CostModel;
 pub use pos::Position;
 pub use slot::Slot;
-use std::{collections::HashSet, fmt, ops::Range};
+use std::{collections::BTreeMap, error::Error, fmt};
+use unicode_normalization::char::canonical_combining_class;
 
 pub trait Diacritic {
     fn renderings(&self) -> pos::PartialMap<&str>;
+
+    fn capacity(&self, _position: Position) -> Option<usize> {
+        None
+    }
+
+    fn stacking_class(&self, position: Position) -> u8 {
+        self.renderings()
+            .data(position)
+            .and_then(|rendering| rendering.chars().next())
+            .map_or(0, canonical_combining_class)
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
@@ -26,13 +47,39 @@ where
         character: char,
         hints: pos::TotalMap<slot::Hint>,
         diacritics: I,
-    ) -> Option<Self>
+    ) -> Result<Self, SolveError>
+    where
+        I: IntoIterator<Item = D>,
+    {
+        Self::solve_with(
+            character,
+            hints,
+            diacritics,
+            &cost::Weights::default(),
+        )
+    }
+
+    pub fn solve_with<I, C>(
+        character: char,
+        hints: pos::TotalMap<slot::Hint>,
+        diacritics: I,
+        cost_model: &C,
+    ) -> Result<Self, SolveError>
     where
         I: IntoIterator<Item = D>,
+        C: CostModel + ?Sized,
     {
-        solve(hints, diacritics)
+        solve_with(hints, diacritics, cost_model)
             .map(|slots| GraphemeCluster { character, slots })
     }
+
+    pub fn normalized(&self, form: normal::Form) -> normal::Normalized<'_, D> {
+        normal::Normalized { cluster: self, form }
+    }
+
+    pub fn svg(&self) -> svg::Preview<'_, D> {
+        svg::Preview::new(self)
+    }
 }
 
 impl<D> fmt::Display for GraphemeCluster<D>
@@ -41,152 +88,425 @@ where
 {
     fn fmt(&self, fmtr: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(fmtr, "{}", self.character)?;
-        for (position, slot) in &self.slots {
-            slot.fmt(position, fmtr)?;
-        }
-        Ok(())
+        fmt_slots(&self.slots, &COMBINING, fmtr)?;
+        fmt_slots(&self.slots, &DOUBLE, fmtr)?;
+        fmt_slots(&self.slots, &[Position::Left, Position::Right], fmtr)
     }
 }
 
 pub fn solve<D, I>(
     hints: pos::TotalMap<slot::Hint>,
     diacritics: I,
-) -> Option<pos::TotalMap<Slot<D>>>
+) -> Result<pos::TotalMap<Slot<D>>, SolveError>
+where
+    I: IntoIterator<Item = D>,
+    D: Diacritic,
+{
+    solve_with(hints, diacritics, &cost::Weights::default())
+}
+
+pub fn solve_with<D, I, C>(
+    hints: pos::TotalMap<slot::Hint>,
+    diacritics: I,
+    cost_model: &C,
+) -> Result<pos::TotalMap<Slot<D>>, SolveError>
 where
     I: IntoIterator<Item = D>,
     D: Diacritic,
+    C: CostModel + ?Sized,
 {
-    Solver::new(hints, diacritics).finish()
+    Solver::new(hints, diacritics, cost_model).finish()
 }
 
+pub fn explain<D, I, C>(
+    hints: pos::TotalMap<slot::Hint>,
+    diacritics: I,
+    cost_model: &C,
+    runners_up: usize,
+) -> Result<explain::Explanation<D>, SolveError>
+where
+    I: IntoIterator<Item = D>,
+    D: Diacritic + Clone,
+    C: CostModel + ?Sized,
+{
+    let solver = Solver::new(hints, diacritics, cost_model);
+    let mut candidates = solver
+        .find_solutions(runners_up + 1)?
+        .into_iter()
+        .map(|solution| explain::Candidate {
+            slots: solution.slots(&solver.diacritics),
+            score: solution.score,
+        });
+    let chosen = candidates.next().expect("solver found no solution");
+    Ok(explain::Explanation { chosen, runners_up: candidates.collect() })
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum SolveError {
+    NoRenderings { diacritic: usize },
+    Excluded { diacritic: usize },
+    CapacityExceeded { diacritic: usize },
+    UnknownMember { diacritic: usize, member: usize },
+}
+
+impl SolveError {
+    fn reindexed(self, indices: &[usize]) -> Self {
+        match self {
+            Self::NoRenderings { diacritic } => {
+                Self::NoRenderings { diacritic: indices[diacritic] }
+            },
+            Self::Excluded { diacritic } => {
+                Self::Excluded { diacritic: indices[diacritic] }
+            },
+            Self::CapacityExceeded { diacritic } => {
+                Self::CapacityExceeded { diacritic: indices[diacritic] }
+            },
+            Self::UnknownMember { diacritic, member } => {
+                Self::UnknownMember { diacritic: indices[diacritic], member }
+            },
+        }
+    }
+}
+
+impl fmt::Display for SolveError {
+    fn fmt(&self, fmtr: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::NoRenderings { diacritic } => {
+                write!(fmtr, "diacritic {} has no renderings", diacritic)
+            },
+            Self::Excluded { diacritic } => write!(
+                fmtr,
+                "all positions of diacritic {} are excluded by hints",
+                diacritic
+            ),
+            Self::CapacityExceeded { diacritic } => write!(
+                fmtr,
+                "no slot has capacity left for diacritic {}",
+                diacritic
+            ),
+            Self::UnknownMember { diacritic, member } => write!(
+                fmtr,
+                "diacritic {} is attached to nonexistent base member {}",
+                diacritic, member
+            ),
+        }
+    }
+}
+
+impl Error for SolveError {}
+
 #[derive(Debug, Clone)]
-struct Solver<D>
+struct Solver<'cost, D, C>
 where
     D: Diacritic,
+    C: CostModel + ?Sized,
 {
     diacritics: Vec<D>,
     hints: pos::TotalMap<slot::Hint>,
+    cost_model: &'cost C,
 }
 
-impl<D> Solver<D>
+impl<'cost, D, C> Solver<'cost, D, C>
 where
     D: Diacritic,
+    C: CostModel + ?Sized,
 {
-    fn new<I>(hints: pos::TotalMap<slot::Hint>, diacritics: I) -> Self
+    fn new<I>(
+        hints: pos::TotalMap<slot::Hint>,
+        diacritics: I,
+        cost_model: &'cost C,
+    ) -> Self
     where
         I: IntoIterator<Item = D>,
     {
         let diacritics = diacritics.into_iter().collect();
-        Self { diacritics, hints }
+        Self { diacritics, hints, cost_model }
     }
 
-    fn find_solution(&self) -> Option<Solution> {
-        let mut best_solution: Option<Solution> = None;
-        let raw_solutions = build_solutions_indices(0 .. self.diacritics.len());
-        for raw_solution in raw_solutions {
-            if let Some(solution) =
-                Solution::new(raw_solution, &self.diacritics, self.hints)
-            {
-                match &best_solution {
-                    Some(found_solution) => {
-                        if solution.points < found_solution.points {
-                            best_solution = Some(solution);
-                        }
-                    },
-                    None => best_solution = Some(solution),
+    fn find_solution(&self) -> Result<Solution, SolveError> {
+        let solutions = self.find_solutions(1)?;
+        Ok(solutions.into_iter().next().expect("solver found no solution"))
+    }
+
+    fn check_placeable(&self) -> Result<(), SolveError> {
+        for (index, diacritic) in self.diacritics.iter().enumerate() {
+            let renderings = diacritic.renderings();
+            if renderings.is_empty() {
+                Err(SolveError::NoRenderings { diacritic: index })?;
+            }
+            let excluded = renderings.iter().all(|(position, _)| {
+                self.hints[position] == slot::Hint::Excluded
+            });
+            if excluded {
+                Err(SolveError::Excluded { diacritic: index })?;
+            }
+        }
+        Ok(())
+    }
+
+    fn find_solutions(
+        &self,
+        count: usize,
+    ) -> Result<Vec<Solution>, SolveError> {
+        self.check_placeable()?;
+
+        let mut states = BTreeMap::new();
+        states.insert(State::default(), vec![Partial::default()]);
+
+        for (diacritic_index, diacritic) in self.diacritics.iter().enumerate() {
+            let renderings = diacritic.renderings();
+            let mut next_states = BTreeMap::<_, Vec<Partial>>::new();
+            for (state, partials) in &states {
+                for (index, (position, _)) in renderings.iter().enumerate() {
+                    if self.hints[position] == slot::Hint::Excluded {
+                        continue;
+                    }
+                    let class = diacritic.stacking_class(position);
+                    let Some(next_state) = state.push(
+                        position,
+                        diacritic.capacity(position),
+                        class,
+                    ) else {
+                        continue;
+                    };
+                    let points = self
+                        .cost_model
+                        .rendering(position, u8::try_from(index).unwrap())
+                        + state.inversions(position, class)
+                            * self.cost_model.inversion(position);
+                    let next_partials =
+                        next_states.entry(next_state).or_default();
+                    for partial in partials {
+                        next_partials.push(partial.push(position, points));
+                    }
+                    next_partials.sort_by_key(|partial| partial.points);
+                    next_partials.truncate(count);
                 }
             }
+            if next_states.is_empty() {
+                Err(SolveError::CapacityExceeded {
+                    diacritic: diacritic_index,
+                })?;
+            }
+            states = next_states;
         }
-        best_solution
+
+        let mut candidates = Vec::new();
+        for (state, partials) in &states {
+            let counts = &state.counts;
+            let mut slot_points = 0;
+            for position in Position::ALL {
+                let mean = mean_slot_len(position, self.diacritics.len());
+                slot_points += self.cost_model.obstruction(
+                    position, self.hints[position], counts[position],
+                );
+                slot_points +=
+                    self.cost_model.balance(position, counts[position], mean);
+            }
+            for partial in partials {
+                candidates.push((partial.points + slot_points, partial));
+            }
+        }
+        candidates.sort_by_key(|(points, _)| *points);
+        candidates.truncate(count);
+
+        let solutions = candidates
+            .into_iter()
+            .map(|(points, partial)| {
+                let mut slot_indices = pos::TotalMap::<Vec<usize>>::default();
+                for (index, position) in partial.positions.iter().enumerate() {
+                    slot_indices[*position].push(index);
+                }
+                arrange(&mut slot_indices, &self.diacritics);
+                let solution = Solution::new(
+                    slot_indices, &self.diacritics, self.hints, self.cost_model,
+                )
+                .expect("solver produced an invalid placement");
+                debug_assert_eq!(solution.score.total(), points);
+                solution
+            })
+            .collect();
+        Ok(solutions)
     }
 
-    fn finish(self) -> Option<pos::TotalMap<Slot<D>>> {
+    fn finish(self) -> Result<pos::TotalMap<Slot<D>>, SolveError> {
         let best_solution = self.find_solution()?;
         let mut diacritics: Vec<_> =
             self.diacritics.into_iter().map(Some).collect();
-        Some(best_solution.slot_indices.map(|indices| {
-            Slot {
-                diacritics: indices
-                    .into_iter()
-                    .map(|index| diacritics[index].take().unwrap())
-                    .collect(),
-            }
+        Ok(best_solution.slot_indices.map(|indices| Slot {
+            diacritics: indices
+                .into_iter()
+                .map(|index| diacritics[index].take().unwrap())
+                .collect(),
         }))
     }
 }
 
-fn build_solutions_indices(
-    mut indices: Range<usize>,
-) -> HashSet<pos::TotalMap<Vec<usize>>> {
-    let mut solutions = HashSet::new();
-    match indices.next() {
-        Some(head) => {
-            let tail_solutions = build_solutions_indices(indices);
-            for position in Position::ALL {
-                for tail_solution in &tail_solutions {
-                    let mut solution: pos::TotalMap<Vec<_>> =
-                        pos::TotalMap::default();
-                    solution[position] = vec![head];
-                    for tail_position in Position::ALL {
-                        solution[tail_position]
-                            .extend_from_slice(&tail_solution[tail_position]);
-                    }
-                    solutions.insert(solution);
-                }
-            }
-        },
-        None => {
-            solutions.insert(pos::TotalMap::default());
-        },
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+struct State {
+    counts: pos::TotalMap<usize>,
+    capacities: pos::TotalMap<Option<usize>>,
+    classes: pos::TotalMap<Vec<u8>>,
+}
+
+impl State {
+    fn push(
+        &self,
+        position: Position,
+        capacity: Option<usize>,
+        class: u8,
+    ) -> Option<Self> {
+        let mut next = self.clone();
+        next.counts[position] += 1;
+        let classes = &mut next.classes[position];
+        let index = classes.partition_point(|placed| *placed <= class);
+        classes.insert(index, class);
+        next.capacities[position] = match (self.capacities[position], capacity)
+        {
+            (Some(current), Some(capacity)) => Some(current.min(capacity)),
+            (current, capacity) => current.or(capacity),
+        };
+        match next.capacities[position] {
+            Some(capacity) if next.counts[position] > capacity => None,
+            _ => Some(next),
+        }
+    }
+
+    fn inversions(&self, position: Position, class: u8) -> u128 {
+        let classes = &self.classes[position];
+        let count =
+            classes.len() - classes.partition_point(|placed| *placed <= class);
+        u128::try_from(count).unwrap()
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+struct Partial {
+    points: u128,
+    positions: Vec<Position>,
+}
+
+impl Partial {
+    fn push(&self, position: Position, points: u128) -> Self {
+        let mut positions = Vec::with_capacity(self.positions.len() + 1);
+        positions.extend_from_slice(&self.positions);
+        positions.push(position);
+        Self { points: self.points + points, positions }
+    }
+}
+
+const COMBINING: [Position; 5] = [
+    Position::Through,
+    Position::Top,
+    Position::TopRight,
+    Position::Bottom,
+    Position::BottomRight,
+];
+
+const DOUBLE: [Position; 2] = [Position::DoubleTop, Position::DoubleBottom];
+
+fn fmt_slots<D>(
+    slots: &pos::TotalMap<Slot<D>>,
+    positions: &[Position],
+    fmtr: &mut fmt::Formatter,
+) -> fmt::Result
+where
+    D: Diacritic,
+{
+    for &position in positions {
+        slots[position].fmt(position, fmtr)?;
+    }
+    Ok(())
+}
+
+fn mean_slot_len(position: Position, diacritics: usize) -> usize {
+    if Position::SIDES.contains(&position) {
+        (diacritics + 2) / 4
+    } else {
+        0
+    }
+}
+
+fn arrange<D>(slot_indices: &mut pos::TotalMap<Vec<usize>>, diacritics: &[D])
+where
+    D: Diacritic,
+{
+    for (position, indices) in slot_indices {
+        indices.sort_by_key(|index| {
+            (diacritics[*index].stacking_class(position), *index)
+        });
     }
-    solutions
 }
 
 #[derive(Debug, Clone)]
 struct Solution {
-    points: u128,
+    score: cost::Score,
     slot_indices: pos::TotalMap<Vec<usize>>,
 }
 
 impl Solution {
-    fn new<D>(
+    fn new<D, C>(
         slot_indices: pos::TotalMap<Vec<usize>>,
         diacritics: &[D],
         slot_hints: pos::TotalMap<slot::Hint>,
+        cost_model: &C,
     ) -> Option<Self>
     where
         D: Diacritic,
+        C: CostModel + ?Sized,
     {
-        let mut points = 0;
-        let mean = (diacritics.len() + 2) / 4;
+        let mut score = cost::Score::default();
         for position in Position::ALL {
-            let mut maybe_previous_index = None;
-            for index in slot_indices[position].iter() {
-                points += diacritics[*index]
+            if slot_hints[position] == slot::Hint::Excluded
+                && !slot_indices[position].is_empty()
+            {
+                None?;
+            }
+            let len = slot_indices[position].len();
+            let mut maybe_previous = None;
+            for (slot_index, index) in slot_indices[position].iter().enumerate()
+            {
+                let diacritic = &diacritics[*index];
+                score.rendering += diacritic
                     .renderings()
                     .to_index(position)
-                    .map(u128::from)?
-                    * 2;
-                if let Some(previous_index) = maybe_previous_index {
-                    if previous_index > *index {
-                        points += 1;
+                    .map(|index| cost_model.rendering(position, index))?;
+                if diacritic.capacity(position).is_some_and(|cap| len > cap) {
+                    None?;
+                }
+                let class = diacritic.stacking_class(position);
+                if let Some(previous_class) = maybe_previous {
+                    if previous_class > class {
+                        None?;
                     }
                 }
-                maybe_previous_index = Some(*index);
+                let inversions = slot_indices[position][.. slot_index]
+                    .iter()
+                    .filter(|previous| **previous > *index)
+                    .count();
+                score.inversion += u128::try_from(inversions).unwrap()
+                    * cost_model.inversion(position);
+                maybe_previous = Some(class);
             }
-            points += match slot_hints[position] {
-                slot::Hint::Regular => 0,
-                slot::Hint::Obstructed => {
-                    u128::try_from(slot_indices[position].len()).unwrap()
-                },
-            };
-            points +=
-                u128::try_from(slot_indices[position].len().abs_diff(mean))
-                    .unwrap()
-                    * 4;
+            score.obstruction +=
+                cost_model.obstruction(position, slot_hints[position], len);
+            let mean = mean_slot_len(position, diacritics.len());
+            score.balance += cost_model.balance(position, len, mean);
         }
 
-        Some(Self { points, slot_indices })
+        Some(Self { score, slot_indices })
+    }
+
+    fn slots<D>(&self, diacritics: &[D]) -> pos::TotalMap<Slot<D>>
+    where
+        D: Diacritic + Clone,
+    {
+        self.slot_indices.as_ref().map(|indices| Slot {
+            diacritics: indices
+                .iter()
+                .map(|index| diacritics[*index].clone())
+                .collect(),
+        })
     }
 }
 
@@ -199,6 +519,41 @@ where
     pub slots: pos::TotalMap<Slot<D>>,
 }
 
+impl<D> Symbol<D>
+where
+    D: Diacritic,
+{
+    pub fn solve<I>(
+        character: char,
+        hints: pos::TotalMap<slot::Hint>,
+        diacritics: I,
+    ) -> Result<Self, SolveError>
+    where
+        I: IntoIterator<Item = D>,
+    {
+        Self::solve_with(
+            character,
+            hints,
+            diacritics,
+            &cost::Weights::default(),
+        )
+    }
+
+    pub fn solve_with<I, C>(
+        character: char,
+        hints: pos::TotalMap<slot::Hint>,
+        diacritics: I,
+        cost_model: &C,
+    ) -> Result<Self, SolveError>
+    where
+        I: IntoIterator<Item = D>,
+        C: CostModel + ?Sized,
+    {
+        solve_with(hints, diacritics, cost_model)
+            .map(|slots| Symbol { character, slots })
+    }
+}
+
 impl<D> fmt::Display for Symbol<D>
 where
     D: Diacritic,
@@ -206,22 +561,169 @@ where
     fn fmt(&self, fmtr: &mut fmt::Formatter) -> fmt::Result {
         self.slots.left.fmt(Position::Left, fmtr)?;
         write!(fmtr, "{}", self.character)?;
-        self.slots.left.fmt(Position::Top, fmtr)?;
-        self.slots.left.fmt(Position::Bottom, fmtr)?;
-        self.slots.left.fmt(Position::Right, fmtr)?;
+        fmt_slots(&self.slots, &COMBINING, fmtr)?;
+        fmt_slots(&self.slots, &DOUBLE, fmtr)?;
+        self.slots.right.fmt(Position::Right, fmtr)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Compound<D>
+where
+    D: Diacritic,
+{
+    pub base: base::Base,
+    pub whole: pos::TotalMap<Slot<D>>,
+    pub members: Vec<pos::TotalMap<Slot<D>>>,
+}
+
+impl<D> Compound<D>
+where
+    D: Diacritic,
+{
+    pub fn solve<I>(base: base::Base, diacritics: I) -> Result<Self, SolveError>
+    where
+        I: IntoIterator<Item = (base::Attachment, D)>,
+    {
+        Self::solve_with(
+            base,
+            slot::metrics::Table::builtin(),
+            diacritics,
+            &cost::Weights::default(),
+        )
+    }
+
+    pub fn solve_with<I, C>(
+        base: base::Base,
+        table: &slot::metrics::Table,
+        diacritics: I,
+        cost_model: &C,
+    ) -> Result<Self, SolveError>
+    where
+        I: IntoIterator<Item = (base::Attachment, D)>,
+        C: CostModel + ?Sized,
+    {
+        let mut groups: Vec<(Vec<usize>, Vec<D>)> =
+            (0 ..= base.len()).map(|_| Default::default()).collect();
+        for (index, (attachment, diacritic)) in
+            diacritics.into_iter().enumerate()
+        {
+            let group = match attachment {
+                base::Attachment::Whole => 0,
+                base::Attachment::Member(member) if member < base.len() => {
+                    member + 1
+                },
+                base::Attachment::Member(member) => {
+                    Err(SolveError::UnknownMember { diacritic: index, member })?
+                },
+            };
+            groups[group].0.push(index);
+            groups[group].1.push(diacritic);
+        }
+
+        let mut slots = groups
+            .into_iter()
+            .enumerate()
+            .map(|(group, (indices, diacritics))| {
+                let hints = match group {
+                    0 => base.hints(table),
+                    _ => base.member_hints(group - 1, table),
+                };
+                let mut hints = hints.unwrap_or_default();
+                if group > 0 || base.len() < 2 {
+                    hints.double_top = slot::Hint::Excluded;
+                    hints.double_bottom = slot::Hint::Excluded;
+                }
+                solve_with(hints, diacritics, cost_model)
+                    .map_err(|error| error.reindexed(&indices))
+            })
+            .collect::<Result<Vec<_>, _>>()?
+            .into_iter();
+        let whole = slots.next().expect("whole group is always present");
+        Ok(Self { base, whole, members: slots.collect() })
+    }
+}
+
+impl<D> fmt::Display for Compound<D>
+where
+    D: Diacritic,
+{
+    fn fmt(&self, fmtr: &mut fmt::Formatter) -> fmt::Result {
+        self.whole.left.fmt(Position::Left, fmtr)?;
+        if self.base.is_empty() {
+            write!(fmtr, "\u{25cc}")?;
+            fmt_slots(&self.whole, &COMBINING, fmtr)?;
+            fmt_slots(&self.whole, &DOUBLE, fmtr)?;
+        }
+        let last = self.base.len().saturating_sub(1);
+        let mut deferred = Vec::new();
+        let members = self.base.characters.iter().zip(&self.members);
+        for (index, (character, slots)) in members.enumerate() {
+            if index > 0 {
+                if let Some(tie) = self.base.tie {
+                    write!(fmtr, "{}", tie.character())?;
+                }
+            }
+            slots.left.fmt(Position::Left, fmtr)?;
+            write!(fmtr, "{}", character)?;
+            fmt_slots(slots, &COMBINING, fmtr)?;
+            if index == last {
+                fmt_slots(&self.whole, &COMBINING, fmtr)?;
+            }
+            fmt_slots(slots, &DOUBLE, fmtr)?;
+            if index == 0 {
+                fmt_slots(&self.whole, &DOUBLE, fmtr)?;
+            }
+            if self.base.tie.is_some() && index < last {
+                deferred.push(&slots.right);
+            } else {
+                slots.right.fmt(Position::Right, fmtr)?;
+            }
+        }
+        for right in deferred {
+            right.fmt(Position::Right, fmtr)?;
+        }
+        self.whole.right.fmt(Position::Right, fmtr)?;
         Ok(())
     }
 }
 
 #[cfg(test)]
 mod test {
-    use crate::{pos, solve, Diacritic, Position, Slot};
+    use crate::{
+        arrange,
+        base::{self, Attachment, Tie},
+        cache::{SolverCache, Statistics},
+        cost,
+        explain,
+        explain::Candidate,
+        normal,
+        parse::{ParseError, Parser, Variants},
+        pos,
+        slot,
+        solve,
+        solve_with,
+        svg,
+        Compound,
+        Diacritic,
+        GraphemeCluster,
+        Position,
+        Slot,
+        Solution,
+        SolveError,
+        Solver,
+        Symbol,
+    };
+    use proptest::prelude::*;
 
     #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
     enum PhoneticDiacritic {
         Nasalized,
         Lowered,
         Voiceless,
+        Labialized,
+        Velarized,
+        WideNasalized,
     }
 
     impl Diacritic for PhoneticDiacritic {
@@ -238,10 +740,36 @@ mod test {
                     (Position::Bottom, "\u{0325}"),
                     (Position::Top, "\u{030a}"),
                 ]),
+                PhoneticDiacritic::Labialized => {
+                    pos::PartialMap::from_iter([(Position::Right, "ʷ")])
+                },
+                PhoneticDiacritic::Velarized => pos::PartialMap::from_iter([
+                    (Position::Through, "\u{0334}"),
+                    (Position::Right, "ˠ"),
+                ]),
+                PhoneticDiacritic::WideNasalized => {
+                    pos::PartialMap::from_iter([
+                        (Position::DoubleTop, "\u{0360}"),
+                        (Position::Top, "\u{0303}"),
+                    ])
+                },
             }
         }
     }
 
+    impl Variants for PhoneticDiacritic {
+        fn variants() -> Vec<Self> {
+            vec![
+                PhoneticDiacritic::Nasalized,
+                PhoneticDiacritic::Lowered,
+                PhoneticDiacritic::Voiceless,
+                PhoneticDiacritic::Labialized,
+                PhoneticDiacritic::Velarized,
+                PhoneticDiacritic::WideNasalized,
+            ]
+        }
+    }
+
     #[test]
     fn single_diacritic() {
         let solution =
@@ -252,6 +780,7 @@ mod test {
             left: Slot { diacritics: Vec::new() },
             bottom: Slot { diacritics: Vec::new() },
             right: Slot { diacritics: Vec::new() },
+            ..pos::TotalMap::default()
         });
     }
 
@@ -269,6 +798,853 @@ mod test {
             left: Slot { diacritics: vec![PhoneticDiacritic::Lowered] },
             bottom: Slot { diacritics: vec![PhoneticDiacritic::Voiceless] },
             right: Slot { diacritics: Vec::new() },
+            ..pos::TotalMap::default()
         });
     }
+
+    #[test]
+    fn custom_weights_prefer_stacking() {
+        let weights = cost::Weights {
+            balance: 0,
+            placement: pos::TotalMap { left: 16, ..pos::TotalMap::default() },
+            ..cost::Weights::default()
+        };
+        let solution = solve_with(
+            pos::TotalMap::default(),
+            [
+                PhoneticDiacritic::Nasalized,
+                PhoneticDiacritic::Lowered,
+                PhoneticDiacritic::Voiceless,
+            ],
+            &weights,
+        )
+        .unwrap();
+
+        assert_eq!(solution, pos::TotalMap {
+            top: Slot { diacritics: vec![PhoneticDiacritic::Nasalized] },
+            left: Slot { diacritics: Vec::new() },
+            bottom: Slot {
+                diacritics: vec![
+                    PhoneticDiacritic::Lowered,
+                    PhoneticDiacritic::Voiceless,
+                ],
+            },
+            right: Slot { diacritics: Vec::new() },
+            ..pos::TotalMap::default()
+        });
+    }
+
+    #[test]
+    fn explain_breaks_down_scores() {
+        let explanation = explain(
+            pos::TotalMap::default(),
+            [
+                PhoneticDiacritic::Nasalized,
+                PhoneticDiacritic::Lowered,
+                PhoneticDiacritic::Voiceless,
+            ],
+            &cost::Weights::default(),
+            2,
+        )
+        .unwrap();
+
+        assert_eq!(explanation.chosen, Candidate {
+            slots: pos::TotalMap {
+                top: Slot { diacritics: vec![PhoneticDiacritic::Nasalized] },
+                left: Slot { diacritics: vec![PhoneticDiacritic::Lowered] },
+                bottom: Slot { diacritics: vec![PhoneticDiacritic::Voiceless] },
+                right: Slot { diacritics: Vec::new() },
+                ..pos::TotalMap::default()
+            },
+            score: cost::Score {
+                rendering: 2,
+                inversion: 0,
+                obstruction: 0,
+                balance: 4,
+            },
+        });
+        assert_eq!(explanation.runners_up, vec![
+            Candidate {
+                slots: pos::TotalMap {
+                    top: Slot {
+                        diacritics: vec![PhoneticDiacritic::Nasalized],
+                    },
+                    left: Slot { diacritics: Vec::new() },
+                    bottom: Slot {
+                        diacritics: vec![
+                            PhoneticDiacritic::Lowered,
+                            PhoneticDiacritic::Voiceless,
+                        ],
+                    },
+                    right: Slot { diacritics: Vec::new() },
+                    ..pos::TotalMap::default()
+                },
+                score: cost::Score {
+                    rendering: 0,
+                    inversion: 0,
+                    obstruction: 0,
+                    balance: 12,
+                },
+            },
+            Candidate {
+                slots: pos::TotalMap {
+                    top: Slot {
+                        diacritics: vec![
+                            PhoneticDiacritic::Nasalized,
+                            PhoneticDiacritic::Voiceless,
+                        ],
+                    },
+                    left: Slot { diacritics: Vec::new() },
+                    bottom: Slot {
+                        diacritics: vec![PhoneticDiacritic::Lowered],
+                    },
+                    right: Slot { diacritics: Vec::new() },
+                    ..pos::TotalMap::default()
+                },
+                score: cost::Score {
+                    rendering: 2,
+                    inversion: 0,
+                    obstruction: 0,
+                    balance: 12,
+                },
+            },
+        ]);
+    }
+
+    #[test]
+    fn no_renderings() {
+        let error = solve(pos::TotalMap::default(), [
+            ArbitraryDiacritic::new(vec![Position::Top]),
+            ArbitraryDiacritic::new(Vec::new()),
+        ])
+        .unwrap_err();
+        assert_eq!(error, SolveError::NoRenderings { diacritic: 1 });
+    }
+
+    #[test]
+    fn excluded_by_hints() {
+        let hints = pos::TotalMap {
+            top: slot::Hint::Excluded,
+            ..pos::TotalMap::default()
+        };
+        let error = solve(hints, [
+            PhoneticDiacritic::Voiceless,
+            PhoneticDiacritic::Nasalized,
+        ])
+        .unwrap_err();
+        assert_eq!(error, SolveError::Excluded { diacritic: 1 });
+    }
+
+    #[test]
+    fn capacity_exceeded() {
+        let mut diacritic = ArbitraryDiacritic::new(vec![Position::Right]);
+        diacritic.capacity = Some(1);
+        let error =
+            solve(pos::TotalMap::default(), [diacritic.clone(), diacritic])
+                .unwrap_err();
+        assert_eq!(error, SolveError::CapacityExceeded { diacritic: 1 });
+    }
+
+    #[test]
+    fn capacity_spreads_diacritics() {
+        let mut first = ArbitraryDiacritic::new(vec![Position::Top]);
+        first.capacity = Some(1);
+        let second =
+            ArbitraryDiacritic::new(vec![Position::Top, Position::Left]);
+        let solution =
+            solve(pos::TotalMap::default(), [first.clone(), second.clone()])
+                .unwrap();
+        assert_eq!(solution.top.diacritics, vec![first]);
+        assert_eq!(solution.left.diacritics, vec![second]);
+    }
+
+    #[test]
+    fn stacking_order() {
+        let weights = cost::Weights { balance: 0, ..cost::Weights::default() };
+        let mut outer = ArbitraryDiacritic::new(vec![Position::Bottom]);
+        outer.class = 220;
+        let mut inner = ArbitraryDiacritic::new(vec![Position::Bottom]);
+        inner.class = 202;
+        let solution = solve_with(
+            pos::TotalMap::default(),
+            [outer.clone(), inner.clone()],
+            &weights,
+        )
+        .unwrap();
+        assert_eq!(solution.bottom.diacritics, vec![inner, outer]);
+    }
+
+    #[test]
+    fn inversions_keep_cheapest_partial() {
+        let diacritic = |positions: &[Position], class| ArbitraryDiacritic {
+            positions: positions.to_vec(),
+            capacity: None,
+            class,
+        };
+        let diacritics = [
+            diacritic(
+                &[Position::Through, Position::Top, Position::DoubleBottom],
+                2,
+            ),
+            diacritic(
+                &[Position::Through, Position::Top, Position::TopRight],
+                0,
+            ),
+            diacritic(&[Position::Through], 0),
+            diacritic(&[Position::TopRight], 0),
+        ];
+        let hints = pos::TotalMap::default();
+        let weights = cost::Weights::default();
+
+        let found = Solver::new(hints, diacritics.clone(), &weights)
+            .find_solution()
+            .map(|solution| solution.score.total());
+        assert_eq!(
+            found.ok(),
+            exhaustive_best_points(hints, &diacritics, &weights)
+        );
+
+        let mut expected = exhaustive_points(hints, &diacritics, &weights);
+        expected.truncate(8);
+        let explanation = explain(hints, diacritics, &weights, 7).unwrap();
+        let mut points = vec![explanation.chosen.score.total()];
+        points.extend(
+            explanation
+                .runners_up
+                .iter()
+                .map(|candidate| candidate.score.total()),
+        );
+        assert_eq!(points, expected);
+    }
+
+    #[test]
+    fn inversions_are_scored() {
+        let mut outer =
+            ArbitraryDiacritic::new(vec![Position::Bottom, Position::Top]);
+        outer.class = 220;
+        let mut inner = ArbitraryDiacritic::new(vec![Position::Bottom]);
+        inner.class = 202;
+
+        let weights = cost::Weights {
+            balance: 0,
+            rendering: 1,
+            inversion: 0,
+            ..cost::Weights::default()
+        };
+        let explanation = explain(
+            pos::TotalMap::default(),
+            [outer.clone(), inner.clone()],
+            &weights,
+            0,
+        )
+        .unwrap();
+        assert_eq!(explanation.chosen.slots.bottom.diacritics, vec![
+            inner.clone(),
+            outer.clone()
+        ]);
+
+        let weights = cost::Weights { inversion: 4, ..weights };
+        let explanation = explain(
+            pos::TotalMap::default(),
+            [outer.clone(), inner.clone()],
+            &weights,
+            1,
+        )
+        .unwrap();
+        assert_eq!(explanation.chosen.slots.top.diacritics, vec![outer]);
+        assert_eq!(explanation.chosen.score.inversion, 0);
+        assert_eq!(explanation.runners_up[0].score.inversion, 4);
+    }
+
+    #[test]
+    fn normalized_forms() {
+        let cluster = GraphemeCluster::solve('e', pos::TotalMap::default(), [
+            PhoneticDiacritic::Nasalized,
+            PhoneticDiacritic::Voiceless,
+        ])
+        .unwrap();
+        assert_eq!(cluster.to_string(), "e\u{0303}\u{0325}");
+        assert_eq!(
+            cluster.normalized(normal::Form::Nfc).to_string(),
+            "\u{1ebd}\u{0325}"
+        );
+        assert_eq!(
+            cluster.normalized(normal::Form::Nfd).to_string(),
+            "e\u{0325}\u{0303}"
+        );
+    }
+
+    #[test]
+    fn parse_round_trip() {
+        let parser = Parser::<PhoneticDiacritic>::from_variants();
+        let clusters = [
+            GraphemeCluster::solve('a', pos::TotalMap::default(), [
+                PhoneticDiacritic::Nasalized,
+                PhoneticDiacritic::Lowered,
+                PhoneticDiacritic::Voiceless,
+            ])
+            .unwrap(),
+            GraphemeCluster::solve('e', pos::TotalMap::default(), [
+                PhoneticDiacritic::Nasalized,
+            ])
+            .unwrap(),
+            GraphemeCluster::solve('k', pos::TotalMap::default(), []).unwrap(),
+        ];
+        let text: String =
+            clusters.iter().map(|cluster| cluster.to_string()).collect();
+        assert_eq!(parser.segment(&text).len(), 3);
+        assert_eq!(parser.parse(&text).unwrap(), clusters);
+
+        let text: String = clusters
+            .iter()
+            .map(|cluster| cluster.normalized(normal::Form::Nfc).to_string())
+            .collect();
+        assert_eq!(parser.parse(&text).unwrap(), clusters);
+    }
+
+    #[test]
+    fn parse_unknown_mark() {
+        let parser = Parser::<PhoneticDiacritic>::from_variants();
+        assert_eq!(
+            parser.parse("ae\u{0303}o\u{0301}"),
+            Err(ParseError::UnknownMark { cluster: 2, mark: '\u{0301}' })
+        );
+    }
+
+    #[test]
+    fn symbol_all_positions() {
+        let symbol = Symbol::solve('a', pos::TotalMap::default(), [
+            PhoneticDiacritic::Nasalized,
+            PhoneticDiacritic::Lowered,
+            PhoneticDiacritic::Voiceless,
+            PhoneticDiacritic::Labialized,
+        ])
+        .unwrap();
+        assert_eq!(symbol.slots, pos::TotalMap {
+            top: Slot { diacritics: vec![PhoneticDiacritic::Nasalized] },
+            left: Slot { diacritics: vec![PhoneticDiacritic::Lowered] },
+            bottom: Slot { diacritics: vec![PhoneticDiacritic::Voiceless] },
+            right: Slot { diacritics: vec![PhoneticDiacritic::Labialized] },
+            ..pos::TotalMap::default()
+        });
+        assert_eq!(symbol.to_string(), "\u{02d5}a\u{0303}\u{0325}ʷ");
+    }
+
+    #[test]
+    fn symbol_prefix_and_suffix() {
+        let symbol = Symbol {
+            character: 'o',
+            slots: pos::TotalMap {
+                top: Slot { diacritics: vec![PhoneticDiacritic::Voiceless] },
+                left: Slot {
+                    diacritics: vec![
+                        PhoneticDiacritic::Lowered,
+                        PhoneticDiacritic::Lowered,
+                    ],
+                },
+                bottom: Slot { diacritics: vec![PhoneticDiacritic::Lowered] },
+                right: Slot { diacritics: vec![PhoneticDiacritic::Labialized] },
+                ..pos::TotalMap::default()
+            },
+        };
+        assert_eq!(symbol.to_string(), "\u{02d5}\u{02d5}o\u{030a}\u{031e}ʷ");
+    }
+
+    #[test]
+    fn compound_tied_base() {
+        let compound =
+            Compound::solve(base::Base::tied(['t', 's'], Tie::Above), [
+                (Attachment::Member(0), PhoneticDiacritic::Voiceless),
+                (Attachment::Whole, PhoneticDiacritic::Nasalized),
+                (Attachment::Whole, PhoneticDiacritic::Labialized),
+            ])
+            .unwrap();
+        assert_eq!(compound.whole, pos::TotalMap {
+            top: Slot { diacritics: vec![PhoneticDiacritic::Nasalized] },
+            left: Slot { diacritics: Vec::new() },
+            bottom: Slot { diacritics: Vec::new() },
+            right: Slot { diacritics: vec![PhoneticDiacritic::Labialized] },
+            ..pos::TotalMap::default()
+        });
+        assert_eq!(compound.members, vec![
+            pos::TotalMap {
+                bottom: Slot { diacritics: vec![PhoneticDiacritic::Voiceless] },
+                ..pos::TotalMap::default()
+            },
+            pos::TotalMap::default(),
+        ]);
+        assert_eq!(compound.to_string(), "t\u{0325}\u{0361}s\u{0303}ʷ");
+    }
+
+    #[test]
+    fn compound_tie_precedes_spacing_marks() {
+        let compound =
+            Compound::solve(base::Base::tied(['t', 's'], Tie::Above), [
+                (Attachment::Member(0), PhoneticDiacritic::Labialized),
+                (Attachment::Member(1), PhoneticDiacritic::Nasalized),
+            ])
+            .unwrap();
+        assert_eq!(compound.to_string(), "t\u{0361}s\u{0303}ʷ");
+
+        let compound = Compound::solve(base::Base::sequence(['m', 'b']), [(
+            Attachment::Member(0),
+            PhoneticDiacritic::Labialized,
+        )])
+        .unwrap();
+        assert_eq!(compound.to_string(), "mʷb");
+    }
+
+    #[test]
+    fn compound_empty_base() {
+        let compound = Compound::solve(base::Base::sequence([]), [
+            (Attachment::Whole, PhoneticDiacritic::Nasalized),
+            (Attachment::Whole, PhoneticDiacritic::Labialized),
+        ])
+        .unwrap();
+        assert_eq!(compound.to_string(), "\u{25cc}\u{0303}ʷ");
+    }
+
+    #[test]
+    fn compound_unknown_member() {
+        let error = Compound::solve(base::Base::sequence(['m', 'b']), [
+            (Attachment::Whole, PhoneticDiacritic::Nasalized),
+            (Attachment::Member(2), PhoneticDiacritic::Voiceless),
+        ])
+        .unwrap_err();
+        assert_eq!(error, SolveError::UnknownMember {
+            diacritic: 1,
+            member: 2
+        });
+    }
+
+    #[test]
+    fn compound_reports_input_index() {
+        let error = Compound::solve(base::Base::sequence(['m', 'b']), [
+            (
+                Attachment::Member(1),
+                ArbitraryDiacritic::new(vec![Position::Top]),
+            ),
+            (Attachment::Whole, ArbitraryDiacritic::new(vec![Position::Top])),
+            (Attachment::Member(1), ArbitraryDiacritic::new(Vec::new())),
+        ])
+        .unwrap_err();
+        assert_eq!(error, SolveError::NoRenderings { diacritic: 2 });
+    }
+
+    #[test]
+    fn cache_memoizes_sequences() {
+        let cache = SolverCache::new();
+        let expected = GraphemeCluster::solve('a', pos::TotalMap::default(), [
+            PhoneticDiacritic::Nasalized,
+            PhoneticDiacritic::Lowered,
+            PhoneticDiacritic::Voiceless,
+        ])
+        .unwrap();
+        let solved = cache.solve('a', pos::TotalMap::default(), [
+            PhoneticDiacritic::Nasalized,
+            PhoneticDiacritic::Lowered,
+            PhoneticDiacritic::Voiceless,
+        ]);
+        assert_eq!(solved, Ok(expected.clone()));
+        assert_eq!(cache.statistics(), Statistics { hits: 0, misses: 1 });
+
+        let solved = cache.solve('a', pos::TotalMap::default(), [
+            PhoneticDiacritic::Nasalized,
+            PhoneticDiacritic::Lowered,
+            PhoneticDiacritic::Voiceless,
+        ]);
+        assert_eq!(solved, Ok(expected));
+        assert_eq!(cache.statistics(), Statistics { hits: 1, misses: 1 });
+
+        let reordered = [
+            PhoneticDiacritic::Voiceless,
+            PhoneticDiacritic::Nasalized,
+            PhoneticDiacritic::Lowered,
+        ];
+        let expected =
+            GraphemeCluster::solve('a', pos::TotalMap::default(), reordered)
+                .unwrap();
+        let solved = cache.solve('a', pos::TotalMap::default(), reordered);
+        assert_eq!(solved, Ok(expected));
+        assert_eq!(cache.statistics(), Statistics { hits: 1, misses: 2 });
+
+        let hints = slot::hints('b').unwrap();
+        cache.solve('b', hints, [PhoneticDiacritic::Nasalized]).unwrap();
+        cache.solve('d', hints, [PhoneticDiacritic::Nasalized]).unwrap();
+        assert_eq!(cache.statistics(), Statistics { hits: 2, misses: 3 });
+        assert_eq!(cache.len(), 3);
+
+        cache.clear();
+        assert!(cache.is_empty());
+        assert_eq!(cache.statistics(), Statistics::default());
+    }
+
+    #[test]
+    fn cache_respects_input_order() {
+        let weights = cost::Weights {
+            rendering: 1,
+            balance: 0,
+            inversion: 4,
+            obstruction: 0,
+            ..cost::Weights::default()
+        };
+        let mut outer =
+            ArbitraryDiacritic::new(vec![Position::Bottom, Position::Top]);
+        outer.class = 2;
+        let inner = ArbitraryDiacritic::new(vec![Position::Bottom]);
+        let cache = SolverCache::with_cost_model(weights);
+        for diacritics in
+            [[outer.clone(), inner.clone()], [inner.clone(), outer.clone()]]
+        {
+            let hints = pos::TotalMap::default();
+            let expected = solve_with(hints, diacritics.clone(), &weights);
+            assert_eq!(cache.solve_slots(hints, diacritics), expected);
+        }
+        assert_eq!(
+            cache.solve_slots(pos::TotalMap::default(), [
+                outer.clone(),
+                inner.clone()
+            ]),
+            Ok(pos::TotalMap {
+                top: Slot { diacritics: vec![outer] },
+                bottom: Slot { diacritics: vec![inner] },
+                ..pos::TotalMap::default()
+            })
+        );
+        assert_eq!(cache.statistics(), Statistics { hits: 1, misses: 2 });
+    }
+
+    #[test]
+    fn cache_reports_input_index() {
+        let cache = SolverCache::new();
+        let diacritics = [
+            ArbitraryDiacritic::new(vec![Position::Top]),
+            ArbitraryDiacritic::new(Vec::new()),
+            ArbitraryDiacritic::new(vec![Position::Right]),
+        ];
+        for _ in 0 .. 2 {
+            assert_eq!(
+                cache.solve_slots(pos::TotalMap::default(), diacritics.clone()),
+                Err(SolveError::NoRenderings { diacritic: 1 })
+            );
+        }
+        assert_eq!(cache.statistics(), Statistics { hits: 1, misses: 1 });
+    }
+
+    #[test]
+    fn cache_shared_between_threads() {
+        let cache = SolverCache::<PhoneticDiacritic>::new();
+        std::thread::scope(|scope| {
+            for _ in 0 .. 4 {
+                scope.spawn(|| {
+                    for diacritic in PhoneticDiacritic::variants() {
+                        cache
+                            .solve('a', pos::TotalMap::default(), [diacritic])
+                            .unwrap();
+                    }
+                });
+            }
+        });
+        let statistics = cache.statistics();
+        let variants = PhoneticDiacritic::variants().len();
+        assert_eq!(statistics.hits + statistics.misses, 4 * variants as u64);
+        assert_eq!(cache.len(), variants);
+    }
+
+    #[test]
+    fn through_respects_overlays() {
+        let plain = GraphemeCluster::solve('l', slot::hints('l').unwrap(), [
+            PhoneticDiacritic::Velarized,
+        ])
+        .unwrap();
+        assert_eq!(plain.slots.through, Slot {
+            diacritics: vec![PhoneticDiacritic::Velarized]
+        });
+        assert_eq!(plain.to_string(), "l\u{0334}");
+
+        let hints = slot::hints('ɫ').unwrap();
+        assert_eq!(hints.through, slot::Hint::Obstructed);
+        let weights =
+            cost::Weights { obstruction: 4, ..cost::Weights::default() };
+        let barred = GraphemeCluster::solve_with(
+            'ɫ',
+            hints,
+            [PhoneticDiacritic::Velarized],
+            &weights,
+        )
+        .unwrap();
+        assert_eq!(barred.slots.right, Slot {
+            diacritics: vec![PhoneticDiacritic::Velarized]
+        });
+        assert_eq!(barred.to_string(), "ɫˠ");
+    }
+
+    #[test]
+    fn double_width_spans_members() {
+        let compound = Compound::solve(base::Base::sequence(['n', 'g']), [
+            (Attachment::Whole, PhoneticDiacritic::WideNasalized),
+            (Attachment::Member(1), PhoneticDiacritic::Voiceless),
+        ])
+        .unwrap();
+        assert_eq!(compound.whole.double_top, Slot {
+            diacritics: vec![PhoneticDiacritic::WideNasalized]
+        });
+        assert_eq!(compound.to_string(), "n\u{0360}g\u{0325}");
+
+        let single = Compound::solve(base::Base::single('n'), [(
+            Attachment::Whole,
+            PhoneticDiacritic::WideNasalized,
+        )])
+        .unwrap();
+        assert!(single.whole.double_top.diacritics.is_empty());
+        assert_eq!(single.to_string(), "n\u{0303}");
+
+        let cluster = GraphemeCluster::solve('n', slot::hints('n').unwrap(), [
+            PhoneticDiacritic::WideNasalized,
+        ])
+        .unwrap();
+        assert_eq!(cluster.to_string(), "n\u{0303}");
+    }
+
+    fn assert_golden(name: &str, rendered: &str) {
+        let path = format!("{}/golden/{}", env!("CARGO_MANIFEST_DIR"), name);
+        if std::env::var_os("UPDATE_GOLDEN").is_some() {
+            std::fs::write(&path, rendered).unwrap();
+        }
+        let expected = std::fs::read_to_string(&path).unwrap();
+        assert_eq!(rendered, expected);
+    }
+
+    #[test]
+    fn svg_preview() {
+        let cluster = GraphemeCluster::solve('a', pos::TotalMap::default(), [
+            PhoneticDiacritic::Nasalized,
+            PhoneticDiacritic::Lowered,
+            PhoneticDiacritic::Voiceless,
+        ])
+        .unwrap();
+        assert_golden("triple.svg", &cluster.svg().to_string());
+    }
+
+    #[test]
+    fn svg_grid() {
+        let clusters = [
+            GraphemeCluster::solve('<', pos::TotalMap::default(), [
+                PhoneticDiacritic::Labialized,
+            ]),
+            GraphemeCluster::solve('l', slot::hints('l').unwrap(), [
+                PhoneticDiacritic::Velarized,
+                PhoneticDiacritic::Voiceless,
+            ]),
+            GraphemeCluster::solve('n', slot::hints('n').unwrap(), [
+                PhoneticDiacritic::WideNasalized,
+            ]),
+        ]
+        .map(Result::unwrap);
+        let preview = svg::Preview::grid(&clusters, 2);
+        assert_golden("grid.svg", &preview.to_string());
+    }
+
+    #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+    struct ArbitraryDiacritic {
+        positions: Vec<Position>,
+        capacity: Option<usize>,
+        class: u8,
+    }
+
+    impl ArbitraryDiacritic {
+        fn new(positions: Vec<Position>) -> Self {
+            Self { positions, capacity: None, class: 0 }
+        }
+    }
+
+    impl Diacritic for ArbitraryDiacritic {
+        fn renderings(&self) -> pos::PartialMap<&str> {
+            self.positions.iter().map(|position| (*position, "")).collect()
+        }
+
+        fn capacity(&self, _position: Position) -> Option<usize> {
+            self.capacity
+        }
+
+        fn stacking_class(&self, _position: Position) -> u8 {
+            self.class
+        }
+    }
+
+    fn arbitrary_hint() -> impl Strategy<Value = slot::Hint> {
+        prop::sample::select(vec![
+            slot::Hint::Regular,
+            slot::Hint::Obstructed,
+            slot::Hint::Excluded,
+        ])
+    }
+
+    fn arbitrary_diacritic() -> impl Strategy<Value = ArbitraryDiacritic> {
+        (
+            prop::sample::subsequence(Position::ALL.to_vec(), 0 ..= 4)
+                .prop_shuffle(),
+            prop::option::of(1 ..= 3usize),
+            0 .. 3u8,
+        )
+            .prop_map(|(positions, capacity, class)| {
+                ArbitraryDiacritic { positions, capacity, class }
+            })
+    }
+
+    fn arbitrary_hints() -> impl Strategy<Value = pos::TotalMap<slot::Hint>> {
+        prop::array::uniform9(arbitrary_hint()).prop_map(|hints| {
+            pos::TotalMap::from_fn(|position| hints[position as usize])
+        })
+    }
+
+    fn build_solutions_indices<D>(
+        diacritics: &[D],
+    ) -> Vec<pos::TotalMap<Vec<usize>>>
+    where
+        D: Diacritic,
+    {
+        let mut solutions = vec![pos::TotalMap::<Vec<usize>>::default()];
+        for (index, diacritic) in diacritics.iter().enumerate() {
+            let renderings = diacritic.renderings();
+            solutions = solutions
+                .iter()
+                .flat_map(|solution| {
+                    renderings.iter().map(move |(position, _)| {
+                        let mut solution = solution.clone();
+                        solution[position].push(index);
+                        solution
+                    })
+                })
+                .collect();
+        }
+        solutions
+    }
+
+    fn arbitrary_weights() -> impl Strategy<Value = cost::Weights> {
+        let weight = || 0 .. 8u128;
+        (
+            weight(),
+            weight(),
+            weight(),
+            weight(),
+            prop::array::uniform9(weight()),
+        )
+            .prop_map(
+                |(rendering, inversion, obstruction, balance, placement)| {
+                    cost::Weights {
+                        rendering,
+                        inversion,
+                        obstruction,
+                        balance,
+                        placement: pos::TotalMap::from_fn(|position| {
+                            placement[position as usize]
+                        }),
+                    }
+                },
+            )
+    }
+
+    fn exhaustive_points<D>(
+        hints: pos::TotalMap<slot::Hint>,
+        diacritics: &[D],
+        weights: &cost::Weights,
+    ) -> Vec<u128>
+    where
+        D: Diacritic,
+    {
+        let mut points: Vec<_> = build_solutions_indices(diacritics)
+            .into_iter()
+            .filter_map(|mut indices| {
+                arrange(&mut indices, diacritics);
+                Solution::new(indices, diacritics, hints, weights)
+            })
+            .map(|solution| solution.score.total())
+            .collect();
+        points.sort();
+        points
+    }
+
+    fn exhaustive_best_points<D>(
+        hints: pos::TotalMap<slot::Hint>,
+        diacritics: &[D],
+        weights: &cost::Weights,
+    ) -> Option<u128>
+    where
+        D: Diacritic,
+    {
+        exhaustive_points(hints, diacritics, weights).into_iter().next()
+    }
+
+    proptest! {
+        #![proptest_config(ProptestConfig::with_cases(2048))]
+
+        #[test]
+        fn matches_exhaustive_search(
+            hints in arbitrary_hints(),
+            diacritics in prop::collection::vec(arbitrary_diacritic(), 0 ..= 6),
+        ) {
+            let weights = cost::Weights::default();
+            let expected = exhaustive_best_points(hints, &diacritics, &weights);
+            let found = Solver::new(hints, diacritics, &weights)
+                .find_solution()
+                .ok()
+                .map(|solution| solution.score.total());
+            prop_assert_eq!(found, expected);
+        }
+
+        #[test]
+        fn matches_exhaustive_search_with_weights(
+            hints in arbitrary_hints(),
+            diacritics in prop::collection::vec(arbitrary_diacritic(), 0 ..= 6),
+            weights in arbitrary_weights(),
+        ) {
+            let expected = exhaustive_best_points(hints, &diacritics, &weights);
+            let found = Solver::new(hints, diacritics, &weights)
+                .find_solution()
+                .ok()
+                .map(|solution| solution.score.total());
+            prop_assert_eq!(found, expected);
+        }
+
+        #[test]
+        fn runners_up_match_exhaustive_ranking(
+            hints in arbitrary_hints(),
+            diacritics in prop::collection::vec(arbitrary_diacritic(), 0 ..= 5),
+            weights in arbitrary_weights(),
+            runners_up in 0 .. 8usize,
+        ) {
+            let mut expected = exhaustive_points(hints, &diacritics, &weights);
+            expected.truncate(runners_up + 1);
+            let found = explain(hints, diacritics, &weights, runners_up)
+                .map(|explanation| {
+                    let mut points = vec![explanation.chosen.score.total()];
+                    points.extend(
+                        explanation
+                            .runners_up
+                            .iter()
+                            .map(|candidate| candidate.score.total()),
+                    );
+                    points
+                })
+                .unwrap_or_default();
+            prop_assert_eq!(found, expected);
+        }
+
+        #[test]
+        fn cache_matches_direct_solve(
+            hints in arbitrary_hints(),
+            diacritics in prop::collection::vec(arbitrary_diacritic(), 0 ..= 5),
+            weights in arbitrary_weights(),
+        ) {
+            let expected = solve_with(hints, diacritics.clone(), &weights);
+            let cache = SolverCache::with_cost_model(weights);
+            for _ in 0 .. 2 {
+                prop_assert_eq!(
+                    cache.solve_slots(hints, diacritics.clone()),
+                    expected.clone()
+                );
+            }
+        }
+    }
 }
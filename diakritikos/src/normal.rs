@@ -0,0 +1,35 @@
+use crate::{Diacritic, GraphemeCluster};
+use std::fmt;
+use unicode_normalization::UnicodeNormalization;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Form {
+    Nfc,
+    Nfd,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct Normalized<'cluster, D>
+where
+    D: Diacritic,
+{
+    pub(crate) cluster: &'cluster GraphemeCluster<D>,
+    pub(crate) form: Form,
+}
+
+impl<D> fmt::Display for Normalized<'_, D>
+where
+    D: Diacritic,
+{
+    fn fmt(&self, fmtr: &mut fmt::Formatter) -> fmt::Result {
+        let rendered = self.cluster.to_string();
+        match self.form {
+            Form::Nfc => {
+                rendered.nfc().try_for_each(|ch| write!(fmtr, "{}", ch))
+            },
+            Form::Nfd => {
+                rendered.nfd().try_for_each(|ch| write!(fmtr, "{}", ch))
+            },
+        }
+    }
+}
@@ -0,0 +1,139 @@
+use crate::{pos, Diacritic, GraphemeCluster, Position, Slot};
+use std::{cmp::Reverse, error::Error, fmt};
+use unicode_normalization::{char::is_combining_mark, UnicodeNormalization};
+
+pub trait Variants: Diacritic + Sized {
+    fn variants() -> Vec<Self>;
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ParseError {
+    Empty,
+    UnknownMark { cluster: usize, mark: char },
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, fmtr: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Empty => write!(fmtr, "empty grapheme cluster"),
+            Self::UnknownMark { cluster, mark } => write!(
+                fmtr,
+                "unknown mark U+{:04X} in grapheme cluster {}",
+                u32::from(*mark),
+                cluster
+            ),
+        }
+    }
+}
+
+impl Error for ParseError {}
+
+#[derive(Debug, Clone)]
+pub struct Parser<D>
+where
+    D: Diacritic,
+{
+    renderings: Vec<(String, Position, D)>,
+}
+
+impl<D> Parser<D>
+where
+    D: Diacritic + Clone,
+{
+    pub fn new<I>(diacritics: I) -> Self
+    where
+        I: IntoIterator<Item = D>,
+    {
+        let mut renderings = Vec::new();
+        for diacritic in diacritics {
+            for (position, rendering) in &diacritic.renderings() {
+                let rendering: String = rendering.nfd().collect();
+                if !rendering.is_empty() {
+                    renderings.push((rendering, position, diacritic.clone()));
+                }
+            }
+        }
+        renderings
+            .sort_by_key(|(rendering, ..)| Reverse(rendering.chars().count()));
+        Self { renderings }
+    }
+
+    pub fn from_variants() -> Self
+    where
+        D: Variants,
+    {
+        Self::new(D::variants())
+    }
+
+    pub fn segment<'text>(&self, text: &'text str) -> Vec<&'text str> {
+        let mut segments = Vec::new();
+        let mut start = None;
+        let mut rest = text;
+        while let Some(character) = rest.chars().next() {
+            let offset = text.len() - rest.len();
+            let attached = is_combining_mark(character)
+                .then(|| character.len_utf8())
+                .or_else(|| self.spacing_prefix(rest));
+            match (start, attached) {
+                (Some(_), Some(len)) => rest = &rest[len ..],
+                _ => {
+                    if let Some(start) = start {
+                        segments.push(&text[start .. offset]);
+                    }
+                    start = Some(offset);
+                    rest = &rest[character.len_utf8() ..];
+                },
+            }
+        }
+        if let Some(start) = start {
+            segments.push(&text[start ..]);
+        }
+        segments
+    }
+
+    pub fn parse(
+        &self,
+        text: &str,
+    ) -> Result<Vec<GraphemeCluster<D>>, ParseError> {
+        self.segment(text)
+            .into_iter()
+            .enumerate()
+            .map(|(index, segment)| {
+                self.parse_cluster(segment).map_err(|error| match error {
+                    ParseError::UnknownMark { mark, .. } => {
+                        ParseError::UnknownMark { cluster: index, mark }
+                    },
+                    error => error,
+                })
+            })
+            .collect()
+    }
+
+    pub fn parse_cluster(
+        &self,
+        text: &str,
+    ) -> Result<GraphemeCluster<D>, ParseError> {
+        let decomposed: String = text.nfd().collect();
+        let mut chars = decomposed.chars();
+        let character = chars.next().ok_or(ParseError::Empty)?;
+        let mut slots = pos::TotalMap::<Slot<D>>::default();
+        let mut rest = chars.as_str();
+        while let Some(mark) = rest.chars().next() {
+            let (rendering, position, diacritic) = self
+                .renderings
+                .iter()
+                .find(|(rendering, ..)| rest.starts_with(rendering.as_str()))
+                .ok_or(ParseError::UnknownMark { cluster: 0, mark })?;
+            slots[*position].diacritics.push(diacritic.clone());
+            rest = &rest[rendering.len() ..];
+        }
+        Ok(GraphemeCluster { character, slots })
+    }
+
+    fn spacing_prefix(&self, text: &str) -> Option<usize> {
+        self.renderings.iter().find_map(|(rendering, position, _)| {
+            (position.is_spacing() && text.starts_with(rendering.as_str()))
+                .then_some(rendering.len())
+        })
+    }
+}
@@ -6,25 +6,61 @@ use std::{
 };
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "snake_case")
+)]
 #[repr(u8)]
 pub enum Position {
     Top,
     Left,
     Bottom,
     Right,
+    Through,
+    TopRight,
+    BottomRight,
+    DoubleTop,
+    DoubleBottom,
 }
 
 impl Position {
-    pub const ALL: [Self; 4] =
+    pub const ALL: [Self; 9] = [
+        Self::Left,
+        Self::Top,
+        Self::Bottom,
+        Self::Right,
+        Self::Through,
+        Self::TopRight,
+        Self::BottomRight,
+        Self::DoubleTop,
+        Self::DoubleBottom,
+    ];
+
+    pub const SIDES: [Self; 4] =
         [Self::Left, Self::Top, Self::Bottom, Self::Right];
+
+    pub fn is_spacing(self) -> bool {
+        matches!(self, Self::Left | Self::Right)
+    }
+
+    pub fn is_double(self) -> bool {
+        matches!(self, Self::DoubleTop | Self::DoubleBottom)
+    }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct TotalMap<T> {
     pub top: T,
     pub left: T,
     pub bottom: T,
     pub right: T,
+    pub through: T,
+    pub top_right: T,
+    pub bottom_right: T,
+    pub double_top: T,
+    pub double_bottom: T,
 }
 
 impl<T> TotalMap<T> {
@@ -37,6 +73,11 @@ impl<T> TotalMap<T> {
             left: function(Position::Left),
             bottom: function(Position::Bottom),
             right: function(Position::Right),
+            through: function(Position::Through),
+            top_right: function(Position::TopRight),
+            bottom_right: function(Position::BottomRight),
+            double_top: function(Position::DoubleTop),
+            double_bottom: function(Position::DoubleBottom),
         }
     }
 
@@ -46,6 +87,11 @@ impl<T> TotalMap<T> {
             left: &self.left,
             bottom: &self.bottom,
             right: &self.right,
+            through: &self.through,
+            top_right: &self.top_right,
+            bottom_right: &self.bottom_right,
+            double_top: &self.double_top,
+            double_bottom: &self.double_bottom,
         }
     }
 
@@ -55,6 +101,11 @@ impl<T> TotalMap<T> {
             left: &mut self.left,
             bottom: &mut self.bottom,
             right: &mut self.right,
+            through: &mut self.through,
+            top_right: &mut self.top_right,
+            bottom_right: &mut self.bottom_right,
+            double_top: &mut self.double_top,
+            double_bottom: &mut self.double_bottom,
         }
     }
 
@@ -74,6 +125,11 @@ impl<T> TotalMap<T> {
             left: mapper(Position::Left, self.left),
             bottom: mapper(Position::Bottom, self.bottom),
             right: mapper(Position::Right, self.right),
+            through: mapper(Position::Through, self.through),
+            top_right: mapper(Position::TopRight, self.top_right),
+            bottom_right: mapper(Position::BottomRight, self.bottom_right),
+            double_top: mapper(Position::DoubleTop, self.double_top),
+            double_bottom: mapper(Position::DoubleBottom, self.double_bottom),
         }
     }
 
@@ -93,6 +149,11 @@ impl<T> TotalMap<Option<T>> {
             left: self.left?,
             bottom: self.bottom?,
             right: self.right?,
+            through: self.through?,
+            top_right: self.top_right?,
+            bottom_right: self.bottom_right?,
+            double_top: self.double_top?,
+            double_bottom: self.double_bottom?,
         })
     }
 }
@@ -106,6 +167,11 @@ impl<T> Index<Position> for TotalMap<T> {
             Position::Bottom => &self.bottom,
             Position::Left => &self.left,
             Position::Right => &self.right,
+            Position::Through => &self.through,
+            Position::TopRight => &self.top_right,
+            Position::BottomRight => &self.bottom_right,
+            Position::DoubleTop => &self.double_top,
+            Position::DoubleBottom => &self.double_bottom,
         }
     }
 }
@@ -117,6 +183,11 @@ impl<T> IndexMut<Position> for TotalMap<T> {
             Position::Bottom => &mut self.bottom,
             Position::Left => &mut self.left,
             Position::Right => &mut self.right,
+            Position::Through => &mut self.through,
+            Position::TopRight => &mut self.top_right,
+            Position::BottomRight => &mut self.bottom_right,
+            Position::DoubleTop => &mut self.double_top,
+            Position::DoubleBottom => &mut self.double_bottom,
         }
     }
 }
@@ -152,7 +223,7 @@ impl<'data, T> IntoIterator for &'data mut TotalMap<T> {
 }
 
 pub struct TotalMapIntoIter<T> {
-    positions: array::IntoIter<Position, 4>,
+    positions: array::IntoIter<Position, { Position::ALL.len() }>,
     map: TotalMap<Option<T>>,
 }
 
@@ -168,7 +239,7 @@ impl<T> Iterator for TotalMapIntoIter<T> {
 #[derive(Debug, Clone, Copy)]
 pub struct PartialMap<T> {
     length: u8,
-    positions: [Option<(Position, T)>; 4],
+    positions: [Option<(Position, T)>; Position::ALL.len()],
 }
 
 impl<T> Default for PartialMap<T> {
@@ -179,7 +250,7 @@ impl<T> Default for PartialMap<T> {
 
 impl<T> PartialMap<T> {
     pub fn empty() -> Self {
-        Self { length: 0, positions: [None, None, None, None] }
+        Self { length: 0, positions: array::from_fn(|_| None) }
     }
 
     pub fn new<I>(positions: I) -> Result<Self, Option<u8>>
@@ -197,7 +268,11 @@ impl<T> PartialMap<T> {
         self.length
     }
 
-    pub fn iter(&self) -> PartialMapIter<T> {
+    pub fn is_empty(&self) -> bool {
+        self.length == 0
+    }
+
+    pub fn iter(&self) -> PartialMapIter<'_, T> {
         self.into_iter()
     }
 
@@ -281,7 +356,7 @@ where
     T: PartialEq,
 {
     fn eq(&self, other: &Self) -> bool {
-        self.into_iter().eq(other.into_iter())
+        self.into_iter().eq(other)
     }
 }
 
@@ -292,7 +367,7 @@ where
     T: PartialOrd,
 {
     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        self.into_iter().partial_cmp(other.into_iter())
+        self.into_iter().partial_cmp(other)
     }
 }
 
@@ -301,7 +376,7 @@ where
     T: Ord,
 {
     fn cmp(&self, other: &Self) -> Ordering {
-        self.into_iter().cmp(other.into_iter())
+        self.into_iter().cmp(other)
     }
 }
 
@@ -322,6 +397,67 @@ where
     }
 }
 
+#[cfg(feature = "serde")]
+impl<T> serde::Serialize for PartialMap<T>
+where
+    T: serde::Serialize,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_map(self)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, T> serde::Deserialize<'de> for PartialMap<T>
+where
+    T: serde::Deserialize<'de>,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        struct Visitor<T>(std::marker::PhantomData<T>);
+
+        impl<'de, T> serde::de::Visitor<'de> for Visitor<T>
+        where
+            T: serde::Deserialize<'de>,
+        {
+            type Value = PartialMap<T>;
+
+            fn expecting(
+                &self,
+                fmtr: &mut std::fmt::Formatter,
+            ) -> std::fmt::Result {
+                write!(fmtr, "a map from positions to data")
+            }
+
+            fn visit_map<A>(
+                self,
+                mut access: A,
+            ) -> Result<Self::Value, A::Error>
+            where
+                A: serde::de::MapAccess<'de>,
+            {
+                let mut map = PartialMap::empty();
+                while let Some((position, data)) = access.next_entry()? {
+                    map.insert(position, data).map_err(|_| {
+                        serde::de::Error::custom(format!(
+                            "duplicated position {:?}",
+                            position
+                        ))
+                    })?;
+                }
+                Ok(map)
+            }
+        }
+
+        deserializer.deserialize_map(Visitor(std::marker::PhantomData))
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct PartialMapIntoIter<T> {
     front: u8,
@@ -1,22 +1,28 @@
+pub mod metrics;
+#[cfg(feature = "font")]
+pub mod font;
+
 use crate::{
     pos::{self, Position},
     Diacritic,
 };
 use std::fmt;
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "lowercase")
+)]
 pub enum Hint {
+    #[default]
     Regular,
     Obstructed,
-}
-
-impl Default for Hint {
-    fn default() -> Self {
-        Hint::Regular
-    }
+    Excluded,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Slot<D>
 where
     D: Diacritic,
@@ -52,17 +58,5 @@ where
 }
 
 pub fn hints(character: char) -> Option<pos::TotalMap<Hint>> {
-    let top_and_bottom = pos::TotalMap::default();
-    let top =
-        pos::TotalMap { top: Hint::Obstructed, ..pos::TotalMap::default() };
-    let bottom =
-        pos::TotalMap { bottom: Hint::Obstructed, ..pos::TotalMap::default() };
-    match character {
-        'a' | 'ɑ' | 'b' | 'c' | 'd' | 'e' | 'ɛ' | 'h' | 'i' | 'k' | 'm'
-        | 'n' | 'o' | 'ɔ' | 'p' | 'q' | 'r' | 'ɹ' | 's' | 'u' | 'v' | 'ʋ'
-        | 'w' | 'ɰ' | 'x' | 'z' => Some(top_and_bottom),
-        'g' | 'j' | 'ŋ' | 'y' => Some(top),
-        'f' | 'l' | 't' => Some(bottom),
-        _ => None,
-    }
+    metrics::Table::builtin().hints(character)
 }
@@ -0,0 +1,159 @@
+use super::{
+    metrics::{Metrics, Table},
+    Hint,
+};
+use crate::pos;
+use std::{error::Error, fmt};
+use ttf_parser::{Face, FaceParsingError};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FontError {
+    Malformed(FaceParsingError),
+}
+
+impl fmt::Display for FontError {
+    fn fmt(&self, fmtr: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Malformed(error) => write!(fmtr, "malformed font: {}", error),
+        }
+    }
+}
+
+impl Error for FontError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::Malformed(error) => Some(error),
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct Font<'data> {
+    face: Face<'data>,
+    fallback: &'data Table,
+    x_height: i32,
+    tolerance: i32,
+}
+
+impl<'data> fmt::Debug for Font<'data> {
+    fn fmt(&self, fmtr: &mut fmt::Formatter) -> fmt::Result {
+        fmtr.debug_struct("Font")
+            .field("x_height", &self.x_height)
+            .field("tolerance", &self.tolerance)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<'data> Font<'data> {
+    pub fn parse(data: &'data [u8]) -> Result<Self, FontError> {
+        Self::parse_with_fallback(data, Table::builtin())
+    }
+
+    pub fn parse_with_fallback(
+        data: &'data [u8],
+        fallback: &'data Table,
+    ) -> Result<Self, FontError> {
+        let face = Face::parse(data, 0).map_err(FontError::Malformed)?;
+        let units_per_em = i32::from(face.units_per_em());
+        let x_height = face
+            .x_height()
+            .map(i32::from)
+            .filter(|height| *height > 0)
+            .or_else(|| {
+                let glyph = face.glyph_index('x')?;
+                face.glyph_bounding_box(glyph).map(|bbox| i32::from(bbox.y_max))
+            })
+            .unwrap_or(units_per_em / 2);
+        Ok(Self { face, fallback, x_height, tolerance: units_per_em / 20 })
+    }
+
+    pub fn supports(&self, character: char) -> bool {
+        self.face.glyph_index(character).is_some()
+    }
+
+    pub fn supports_rendering(&self, rendering: &str) -> bool {
+        rendering.chars().all(|character| self.supports(character))
+    }
+
+    pub fn metrics(&self, character: char) -> Option<Metrics> {
+        let glyph = self.face.glyph_index(character)?;
+        let advance = i32::from(self.face.glyph_hor_advance(glyph)?);
+        let overlay =
+            self.fallback.get(character).is_some_and(|metrics| metrics.overlay);
+        let Some(bbox) = self.face.glyph_bounding_box(glyph) else {
+            return Some(Metrics { overlay, ..Metrics::default() });
+        };
+        Some(Metrics {
+            ascender: i32::from(bbox.y_max) > self.x_height + self.tolerance,
+            descender: i32::from(bbox.y_min) < -self.tolerance,
+            left: i32::from(bbox.x_min) < -self.tolerance,
+            right: i32::from(bbox.x_max) > advance + self.tolerance,
+            overlay,
+        })
+    }
+
+    pub fn hints(&self, character: char) -> Option<pos::TotalMap<Hint>> {
+        self.metrics(character)
+            .or_else(|| self.fallback.get(character))
+            .map(Metrics::hints)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{Font, FontError};
+    use crate::slot::metrics::{Metrics, Table};
+
+    const BOXES: &[u8] = include_bytes!(concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/tests/fonts/boxes.ttf"
+    ));
+
+    #[test]
+    fn malformed_font() {
+        let error = Font::parse(b"not a font").unwrap_err();
+        assert!(matches!(error, FontError::Malformed(_)));
+    }
+
+    #[test]
+    fn glyph_bounding_boxes() {
+        let font = Font::parse(BOXES).unwrap();
+        assert_eq!(font.metrics('a'), Some(Metrics::default()));
+        assert_eq!(
+            font.metrics('b'),
+            Some(Metrics { ascender: true, ..Metrics::default() })
+        );
+        assert_eq!(
+            font.metrics('p'),
+            Some(Metrics { descender: true, ..Metrics::default() })
+        );
+        assert_eq!(
+            font.metrics('ɨ'),
+            Some(Metrics {
+                ascender: true,
+                overlay: true,
+                ..Metrics::default()
+            })
+        );
+        assert!(font.supports('\u{0303}'));
+        assert!(font.supports_rendering("\u{0325}\u{030a}"));
+        assert!(!font.supports('q'));
+    }
+
+    #[test]
+    fn falls_back_to_table() {
+        let mut table = Table::empty();
+        table.register('\u{e000}', Metrics {
+            descender: true,
+            ..Metrics::default()
+        });
+        let font = Font::parse_with_fallback(BOXES, &table).unwrap();
+        assert!(!font.supports('\u{e000}'));
+        assert_eq!(font.metrics('\u{e000}'), None);
+        assert_eq!(
+            font.hints('\u{e000}'),
+            Some(Metrics { descender: true, ..Metrics::default() }.hints())
+        );
+        assert_eq!(font.hints('\u{e001}'), None);
+    }
+}
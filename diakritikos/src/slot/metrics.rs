@@ -0,0 +1,323 @@
+use super::Hint;
+use crate::pos;
+use std::{collections::HashMap, ops::RangeInclusive, sync::OnceLock};
+use unicode_normalization::char::{
+    canonical_combining_class,
+    decompose_canonical,
+};
+
+const REGULAR: &str = concat!(
+    "aceimnorsuvwxzæøıĸœ",
+    "ƨƶƽǝȼɂɇɍ",
+    "ɐɑɒɔɕɘəɚɛɜɝɞɢɤɨɩɪɯɴɵɶɷɹɾɿʀʁʉʊʋʌʍʏʑʙʚʜʟʬʭ",
+    "αεικνοπστυωϖϰϲϵͷͻͼͽϧϩϫϭϯ",
+    "авгежзиклмнопстхчшъыьэюяєѕіљњѡѥѧѩѫѭѳѵѻҍғҝҡҥҹҽӕәөӿ",
+    "ԅԉԋԍԏԑԕԙԝԟԫ",
+    "ỽ",
+);
+
+const ASCENDER: &str = concat!(
+    "ABCDEFGHIJKLMNOPRSTUVWXYZ", "bdfhklt0123456789ßðđħŀłŧſŉǅǲǳ",
+    "ƀƃƅƈƌƕƙƚƛƭƻƾǆȣȴȶ", "ɓɗɦɫɬɺʔʕʖʗʘʛʡʢʣʥʦʨʪʫ", "δθλϐϑͱͳʹ", "бћѣѽѿґҟһӏԁԃ",
+    "ẚẜẝẟỻ",
+);
+
+const DESCENDER: &str = concat!(
+    "gjpqyŋĳ", "ƍƞƣƹƺƿǌǥȝȥȵȷȿɀɉɋɏ", "ɟɡɣɥɰɱɲɳɻɼɽʂʅʇʎʐʒʓʝʞʮʯ",
+    "γημρςχϱϗϙϛϝϡϻϳϣϥͺ", "друцщјџѹҁҋҏҕҗҙқңҧҩҫҭүұҳҵҷҿӄӆӈӊӌӎӡӷӻӽ", "ԇԓԗԛԡԣԥԩԭԯ",
+    "ỿ",
+);
+
+const ASCENDER_DESCENDER: &str = concat!(
+    "Qþƒƥƪƫƴǀǁǂǃǈǉǋȡȸȹ", "ɖɠɧɭɮɸʃʄʆʈʠʤʧʩ", "βζξφψϕϸϼϟ", "фђѯѱԧ",
+    "ƑƝƮДЦЩЏҀҊҖҘҚҢҦҨҪҬҲҴҶҾӃӅӇӉӋӍӶӺӼ",
+);
+
+const OVERLAY: &str = "øđħłŧƀƚƛƶǥȼɇɉɍɏɟɨɫɬɵʄʉ";
+
+const LEFT: &str = "jȷɉɟʄʝƒϳј";
+
+const RIGHT: &str = "ɖɚɝɭɳɻɽʂʈʐ";
+
+const COVERED: [RangeInclusive<char>; 11] = [
+    'A' ..= 'Z',
+    'a' ..= 'z',
+    '\u{00c0}' ..= '\u{00ff}',
+    '\u{0100}' ..= '\u{017f}',
+    '\u{0180}' ..= '\u{024f}',
+    '\u{0250}' ..= '\u{02af}',
+    '\u{0370}' ..= '\u{03ff}',
+    '\u{0400}' ..= '\u{04ff}',
+    '\u{0500}' ..= '\u{052f}',
+    '\u{1e00}' ..= '\u{1eff}',
+    '\u{1f00}' ..= '\u{1fff}',
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Metrics {
+    pub ascender: bool,
+    pub descender: bool,
+    pub left: bool,
+    pub right: bool,
+    pub overlay: bool,
+}
+
+impl Metrics {
+    pub fn hints(self) -> pos::TotalMap<Hint> {
+        let mut hints = self.spanning_hints();
+        hints.double_top = Hint::Excluded;
+        hints.double_bottom = Hint::Excluded;
+        hints
+    }
+
+    pub fn spanning_hints(self) -> pos::TotalMap<Hint> {
+        let hint = |obstructed| {
+            if obstructed {
+                Hint::Obstructed
+            } else {
+                Hint::Regular
+            }
+        };
+        pos::TotalMap {
+            top: hint(self.ascender),
+            left: hint(self.left),
+            bottom: hint(self.descender),
+            right: hint(self.right),
+            through: hint(self.overlay),
+            top_right: hint(self.ascender || self.right),
+            bottom_right: hint(self.descender || self.right),
+            double_top: hint(self.ascender),
+            double_bottom: hint(self.descender),
+        }
+    }
+
+    pub(crate) fn union(self, other: Self) -> Self {
+        Self {
+            ascender: self.ascender || other.ascender,
+            descender: self.descender || other.descender,
+            left: self.left || other.left,
+            right: self.right || other.right,
+            overlay: self.overlay || other.overlay,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Table {
+    entries: HashMap<char, Metrics>,
+}
+
+impl Default for Table {
+    fn default() -> Self {
+        Self::builtin().clone()
+    }
+}
+
+impl Table {
+    pub fn empty() -> Self {
+        Self { entries: HashMap::new() }
+    }
+
+    pub fn builtin() -> &'static Self {
+        static BUILTIN: OnceLock<Table> = OnceLock::new();
+        BUILTIN.get_or_init(Self::build)
+    }
+
+    pub fn get(&self, character: char) -> Option<Metrics> {
+        self.entries.get(&character).copied()
+    }
+
+    pub fn hints(&self, character: char) -> Option<pos::TotalMap<Hint>> {
+        self.get(character).map(Metrics::hints)
+    }
+
+    pub fn register(
+        &mut self,
+        character: char,
+        metrics: Metrics,
+    ) -> Option<Metrics> {
+        self.entries.insert(character, metrics)
+    }
+
+    fn build() -> Self {
+        let mut this = Self::empty();
+        let classes = [
+            (REGULAR, Metrics::default()),
+            (ASCENDER, Metrics { ascender: true, ..Metrics::default() }),
+            (DESCENDER, Metrics { descender: true, ..Metrics::default() }),
+            (ASCENDER_DESCENDER, Metrics {
+                ascender: true,
+                descender: true,
+                ..Metrics::default()
+            }),
+        ];
+        for (characters, metrics) in classes {
+            for character in characters.chars() {
+                this.register(character, metrics);
+            }
+        }
+        for character in LEFT.chars() {
+            this.entries.entry(character).or_default().left = true;
+        }
+        for character in RIGHT.chars() {
+            this.entries.entry(character).or_default().right = true;
+        }
+        for character in OVERLAY.chars() {
+            this.entries.entry(character).or_default().overlay = true;
+        }
+
+        for character in COVERED.into_iter().flatten() {
+            if this.entries.contains_key(&character) {
+                continue;
+            }
+            let metrics = this.decomposed(character).or_else(|| {
+                character
+                    .is_uppercase()
+                    .then(|| Metrics { ascender: true, ..Metrics::default() })
+            });
+            if let Some(metrics) = metrics {
+                this.register(character, metrics);
+            }
+        }
+
+        this
+    }
+
+    fn decomposed(&self, character: char) -> Option<Metrics> {
+        let mut components = Vec::new();
+        decompose_canonical(character, |component| components.push(component));
+        let (&base, marks) = components.split_first()?;
+        if base == character {
+            return None;
+        }
+        let mut metrics = self.get(base)?;
+        for &mark in marks {
+            let mark_metrics = match canonical_combining_class(mark) {
+                214 | 216 | 230 | 232 | 234 => {
+                    Metrics { ascender: true, ..Metrics::default() }
+                },
+                202 | 218 | 220 | 222 | 233 | 240 => {
+                    Metrics { descender: true, ..Metrics::default() }
+                },
+                1 => Metrics { overlay: true, ..Metrics::default() },
+                _ => Metrics::default(),
+            };
+            metrics = metrics.union(mark_metrics);
+        }
+        Some(metrics)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{Metrics, Table, COVERED};
+    use crate::{pos, slot::Hint};
+
+    #[test]
+    fn ipa_letters() {
+        let table = Table::builtin();
+        for character in "ɸβɣɶɒøəɤɨʉɯ".chars() {
+            assert!(table.get(character).is_some(), "{}", character);
+        }
+        assert_eq!(
+            table.get('ɸ'),
+            Some(Metrics {
+                ascender: true,
+                descender: true,
+                ..Metrics::default()
+            })
+        );
+        assert_eq!(table.get('ə'), Some(Metrics::default()));
+        assert_eq!(
+            table.get('ɨ'),
+            Some(Metrics { overlay: true, ..Metrics::default() })
+        );
+    }
+
+    #[test]
+    fn precomposed_letters() {
+        let table = Table::builtin();
+        assert_eq!(
+            table.get('ẽ'),
+            Some(Metrics { ascender: true, ..Metrics::default() })
+        );
+        assert_eq!(
+            table.get('ệ'),
+            Some(Metrics {
+                ascender: true,
+                descender: true,
+                ..Metrics::default()
+            })
+        );
+        assert_eq!(
+            table.get('ǰ'),
+            Some(Metrics {
+                ascender: true,
+                descender: true,
+                left: true,
+                ..Metrics::default()
+            })
+        );
+        assert_eq!(
+            table.get('ǿ'),
+            Some(Metrics {
+                ascender: true,
+                overlay: true,
+                ..Metrics::default()
+            })
+        );
+        assert_eq!(
+            table.get('Ж'),
+            Some(Metrics { ascender: true, ..Metrics::default() })
+        );
+    }
+
+    #[test]
+    fn covered_letters() {
+        let table = Table::builtin();
+        let missing: String = COVERED
+            .into_iter()
+            .flatten()
+            .filter(|character| character.is_alphabetic())
+            .filter(|character| table.get(*character).is_none())
+            .collect();
+        assert_eq!(missing, "");
+        assert_eq!(
+            table.get('A'),
+            Some(Metrics { ascender: true, ..Metrics::default() })
+        );
+        assert_eq!(
+            table.get('Q'),
+            Some(Metrics {
+                ascender: true,
+                descender: true,
+                ..Metrics::default()
+            })
+        );
+    }
+
+    #[test]
+    fn register_custom() {
+        let mut table = Table::default();
+        assert_eq!(table.hints('\u{e000}'), None);
+        table.register('\u{e000}', Metrics {
+            descender: true,
+            right: true,
+            ..Metrics::default()
+        });
+        assert_eq!(
+            table.hints('\u{e000}'),
+            Some(pos::TotalMap {
+                top: Hint::Regular,
+                left: Hint::Regular,
+                bottom: Hint::Obstructed,
+                right: Hint::Obstructed,
+                through: Hint::Regular,
+                top_right: Hint::Obstructed,
+                bottom_right: Hint::Obstructed,
+                double_top: Hint::Excluded,
+                double_bottom: Hint::Excluded,
+            })
+        );
+    }
+}
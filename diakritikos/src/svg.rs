@@ -0,0 +1,191 @@
+use crate::{Diacritic, GraphemeCluster, Position, Slot};
+use std::fmt;
+
+const CELL: u32 = 60;
+const COLUMNS: u32 = 3;
+const ROWS: u32 = 4;
+
+const LAYOUT: [(Position, u32, u32); 9] = [
+    (Position::DoubleTop, 0, 0),
+    (Position::Top, 1, 0),
+    (Position::TopRight, 2, 0),
+    (Position::Left, 0, 1),
+    (Position::Right, 2, 1),
+    (Position::DoubleBottom, 0, 2),
+    (Position::Bottom, 1, 2),
+    (Position::BottomRight, 2, 2),
+    (Position::Through, 0, 3),
+];
+
+#[derive(Debug, Clone)]
+pub struct Preview<'cluster, D>
+where
+    D: Diacritic,
+{
+    clusters: Vec<&'cluster GraphemeCluster<D>>,
+    columns: usize,
+}
+
+impl<'cluster, D> Preview<'cluster, D>
+where
+    D: Diacritic,
+{
+    pub fn new(cluster: &'cluster GraphemeCluster<D>) -> Self {
+        Self { clusters: vec![cluster], columns: 1 }
+    }
+
+    pub fn grid<I>(clusters: I, columns: usize) -> Self
+    where
+        I: IntoIterator<Item = &'cluster GraphemeCluster<D>>,
+    {
+        Self {
+            clusters: clusters.into_iter().collect(),
+            columns: columns.max(1),
+        }
+    }
+
+    fn fmt_cell(
+        &self,
+        cluster: &GraphemeCluster<D>,
+        x: u32,
+        y: u32,
+        fmtr: &mut fmt::Formatter,
+    ) -> fmt::Result {
+        writeln!(
+            fmtr,
+            concat!(
+                r#"<rect x="{}" y="{}" width="{}" height="{}" "#,
+                r##"fill="none" stroke="#cccccc"/>"##
+            ),
+            x,
+            y,
+            CELL * COLUMNS,
+            CELL * ROWS
+        )?;
+        fmt_text(fmtr, x + CELL, y + CELL, 40, &cluster.character.to_string())?;
+        for (position, column, row) in LAYOUT {
+            let slot = &cluster.slots[position];
+            if slot.diacritics.is_empty() {
+                continue;
+            }
+            let cell_x = x + column * CELL;
+            let cell_y = y + row * CELL;
+            fmt_text(fmtr, cell_x, cell_y, 24, &marks(slot, position))?;
+            fmt_label(fmtr, cell_x, cell_y, position_name(position))?;
+        }
+        let cell_y = y + 3 * CELL;
+        fmt_text(fmtr, x + CELL, cell_y, 24, &cluster.to_string())?;
+        fmt_label(fmtr, x + CELL, cell_y, "cluster")
+    }
+}
+
+impl<D> fmt::Display for Preview<'_, D>
+where
+    D: Diacritic,
+{
+    fn fmt(&self, fmtr: &mut fmt::Formatter) -> fmt::Result {
+        let columns = self.columns.min(self.clusters.len()).max(1);
+        let rows = self.clusters.len().div_ceil(self.columns).max(1);
+        let width = u32::try_from(columns).unwrap() * CELL * COLUMNS;
+        let height = u32::try_from(rows).unwrap() * CELL * ROWS;
+        writeln!(
+            fmtr,
+            concat!(
+                r#"<svg xmlns="http://www.w3.org/2000/svg" "#,
+                r#"width="{0}" height="{1}" viewBox="0 0 {0} {1}" "#,
+                r#"font-family="sans-serif" text-anchor="middle">"#
+            ),
+            width, height
+        )?;
+        for (index, cluster) in self.clusters.iter().enumerate() {
+            let column = u32::try_from(index % self.columns).unwrap();
+            let row = u32::try_from(index / self.columns).unwrap();
+            self.fmt_cell(
+                cluster,
+                column * CELL * COLUMNS,
+                row * CELL * ROWS,
+                fmtr,
+            )?;
+        }
+        writeln!(fmtr, "</svg>")
+    }
+}
+
+fn marks<D>(slot: &Slot<D>, position: Position) -> String
+where
+    D: Diacritic,
+{
+    let mut rendered = String::new();
+    for diacritic in &slot.diacritics {
+        if let Some(rendering) = diacritic.renderings().data(position) {
+            if !position.is_spacing() {
+                rendered.push('\u{25cc}');
+            }
+            rendered.push_str(rendering);
+        }
+    }
+    rendered
+}
+
+fn position_name(position: Position) -> &'static str {
+    match position {
+        Position::Top => "top",
+        Position::Left => "left",
+        Position::Bottom => "bottom",
+        Position::Right => "right",
+        Position::Through => "through",
+        Position::TopRight => "top_right",
+        Position::BottomRight => "bottom_right",
+        Position::DoubleTop => "double_top",
+        Position::DoubleBottom => "double_bottom",
+    }
+}
+
+fn fmt_text(
+    fmtr: &mut fmt::Formatter,
+    x: u32,
+    y: u32,
+    size: u32,
+    text: &str,
+) -> fmt::Result {
+    writeln!(
+        fmtr,
+        r#"<text x="{}" y="{}" font-size="{}">{}</text>"#,
+        x + CELL / 2,
+        y + CELL * 3 / 5,
+        size,
+        Escaped(text)
+    )
+}
+
+fn fmt_label(
+    fmtr: &mut fmt::Formatter,
+    x: u32,
+    y: u32,
+    label: &str,
+) -> fmt::Result {
+    writeln!(
+        fmtr,
+        r##"<text x="{}" y="{}" font-size="10" fill="#666666">{}</text>"##,
+        x + CELL / 2,
+        y + CELL * 9 / 10,
+        label
+    )
+}
+
+struct Escaped<'text>(&'text str);
+
+impl fmt::Display for Escaped<'_> {
+    fn fmt(&self, fmtr: &mut fmt::Formatter) -> fmt::Result {
+        for character in self.0.chars() {
+            match character {
+                '&' => write!(fmtr, "&amp;")?,
+                '<' => write!(fmtr, "&lt;")?,
+                '>' => write!(fmtr, "&gt;")?,
+                '"' => write!(fmtr, "&quot;")?,
+                _ => write!(fmtr, "{}", character)?,
+            }
+        }
+        Ok(())
+    }
+}
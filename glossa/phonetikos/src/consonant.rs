@@ -1,35 +1,123 @@
-use crate::{diacritic::Diacritic, Cavity, Phonation};
-use diakritikos::{slot, GraphemeCluster};
+use crate::{diacritic::Diacritic, unsolved, Cavity, Phonation};
+use diakritikos::{
+    base::{Attachment, Base, Tie},
+    pos,
+    Compound,
+    SolveError,
+};
 use std::fmt;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub enum Place {
-    Labial,
+    Bilabial,
+    Labiodental,
+    Dental,
     Alveolar,
+    Postalveolar,
+    Retroflex,
+    Palatal,
     Velar,
+    Uvular,
+    Pharyngeal,
+    Glottal,
+    LabialVelar,
 }
 
+impl Place {
+    pub const ALL: [Self; 12] = [
+        Self::Bilabial,
+        Self::Labiodental,
+        Self::Dental,
+        Self::Alveolar,
+        Self::Postalveolar,
+        Self::Retroflex,
+        Self::Palatal,
+        Self::Velar,
+        Self::Uvular,
+        Self::Pharyngeal,
+        Self::Glottal,
+        Self::LabialVelar,
+    ];
+}
+
+/// Where a place has no IPA letter for a manner, the phone is written with
+/// the letter of a related manner plus a project-specific mark that is not
+/// IPA: `ʳ` for trills, `ˢ` for sibilants, `ˡ` for laterals and `ᵑ` for
+/// nasals.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub enum Manner {
     Plosive,
+    Nasal,
+    Trill,
+    Tap,
+    SibilantAffricate,
+    Affricate,
+    LateralAffricate,
+    SibilantFricative,
     Fricative,
+    LateralFricative,
     Approximant,
+    LateralApproximant,
 }
 
 impl Manner {
+    pub const ALL: [Self; 12] = [
+        Self::Plosive,
+        Self::Nasal,
+        Self::Trill,
+        Self::Tap,
+        Self::SibilantAffricate,
+        Self::Affricate,
+        Self::LateralAffricate,
+        Self::SibilantFricative,
+        Self::Fricative,
+        Self::LateralFricative,
+        Self::Approximant,
+        Self::LateralApproximant,
+    ];
+
+    pub fn is_sibilant(self) -> bool {
+        matches!(self, Self::SibilantAffricate | Self::SibilantFricative)
+    }
+
+    pub fn is_lateral(self) -> bool {
+        matches!(
+            self,
+            Self::LateralAffricate
+                | Self::LateralFricative
+                | Self::LateralApproximant
+        )
+    }
+
     pub fn try_lenit(self) -> Option<Self> {
         match self {
-            Self::Plosive => Some(Self::Fricative),
-            Self::Fricative => Some(Self::Approximant),
-            Self::Approximant => None,
+            Self::Plosive => Some(Self::Affricate),
+            Self::Nasal => None,
+            Self::Trill => Some(Self::Tap),
+            Self::Tap => Some(Self::Approximant),
+            Self::SibilantAffricate => Some(Self::SibilantFricative),
+            Self::Affricate => Some(Self::Fricative),
+            Self::LateralAffricate => Some(Self::LateralFricative),
+            Self::SibilantFricative | Self::Fricative => {
+                Some(Self::Approximant)
+            },
+            Self::LateralFricative => Some(Self::LateralApproximant),
+            Self::Approximant | Self::LateralApproximant => None,
         }
     }
 
     pub fn try_fortify(self) -> Option<Self> {
         match self {
-            Self::Plosive => None,
-            Self::Fricative => Some(Self::Plosive),
+            Self::Plosive | Self::Nasal | Self::Trill => None,
+            Self::Tap => Some(Self::Trill),
+            Self::SibilantAffricate
+            | Self::Affricate
+            | Self::LateralAffricate => Some(Self::Plosive),
+            Self::SibilantFricative => Some(Self::SibilantAffricate),
+            Self::Fricative => Some(Self::Affricate),
+            Self::LateralFricative => Some(Self::LateralAffricate),
             Self::Approximant => Some(Self::Fricative),
+            Self::LateralApproximant => Some(Self::LateralFricative),
         }
     }
 
@@ -46,81 +134,591 @@ impl Manner {
     }
 }
 
+/// Implosives and clicks without an IPA letter are written with the pulmonic
+/// letter plus a project-specific mark that is not IPA: `↓` for implosives
+/// and `ꜝ` for clicks.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Airstream {
+    Pulmonic,
+    Ejective,
+    Implosive,
+    Click,
+}
+
+impl Airstream {
+    pub const ALL: [Self; 4] =
+        [Self::Pulmonic, Self::Ejective, Self::Implosive, Self::Click];
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Secondary {
+    Prenasalized,
+    Preaspirated,
+    Labialized,
+    Palatalized,
+    Velarized,
+    Pharyngealized,
+    Postaspirated,
+}
+
+impl Secondary {
+    pub const ALL: [Self; 7] = [
+        Self::Prenasalized,
+        Self::Preaspirated,
+        Self::Labialized,
+        Self::Palatalized,
+        Self::Velarized,
+        Self::Pharyngealized,
+        Self::Postaspirated,
+    ];
+
+    pub(crate) fn diacritic(self) -> Diacritic {
+        match self {
+            Self::Prenasalized => Diacritic::Prenasalized,
+            Self::Preaspirated => Diacritic::Preaspirated,
+            Self::Labialized => Diacritic::Labialized,
+            Self::Palatalized => Diacritic::Palatalized,
+            Self::Velarized => Diacritic::Velarized,
+            Self::Pharyngealized => Diacritic::Pharyngealized,
+            Self::Postaspirated => Diacritic::Aspirated,
+        }
+    }
+
+    fn bit(self) -> u8 {
+        1 << self as u8
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Secondaries {
+    bits: u8,
+}
+
+impl Secondaries {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn is_empty(self) -> bool {
+        self.bits == 0
+    }
+
+    pub fn len(self) -> usize {
+        self.bits.count_ones() as usize
+    }
+
+    pub fn contains(self, secondary: Secondary) -> bool {
+        self.bits & secondary.bit() != 0
+    }
+
+    pub fn insert(&mut self, secondary: Secondary) -> bool {
+        let inserted = !self.contains(secondary);
+        self.bits |= secondary.bit();
+        inserted
+    }
+
+    pub fn remove(&mut self, secondary: Secondary) -> bool {
+        let removed = self.contains(secondary);
+        self.bits &= !secondary.bit();
+        removed
+    }
+
+    pub fn iter(self) -> impl Iterator<Item = Secondary> {
+        Secondary::ALL
+            .into_iter()
+            .filter(move |secondary| self.contains(*secondary))
+    }
+}
+
+impl FromIterator<Secondary> for Secondaries {
+    fn from_iter<I>(iter: I) -> Self
+    where
+        I: IntoIterator<Item = Secondary>,
+    {
+        let mut secondaries = Self::new();
+        secondaries.extend(iter);
+        secondaries
+    }
+}
+
+impl Extend<Secondary> for Secondaries {
+    fn extend<I>(&mut self, iter: I)
+    where
+        I: IntoIterator<Item = Secondary>,
+    {
+        for secondary in iter {
+            self.insert(secondary);
+        }
+    }
+}
+
+impl<const N: usize> From<[Secondary; N]> for Secondaries {
+    fn from(secondaries: [Secondary; N]) -> Self {
+        secondaries.into_iter().collect()
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct Consonant {
     pub place: Place,
     pub manner: Manner,
+    pub airstream: Airstream,
+    pub secondary: Secondaries,
     pub phonation: Phonation,
     pub cavity: Cavity,
     pub syllabic: bool,
 }
 
+pub(crate) type Letters = (Option<Letter>, Option<Letter>);
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct Letter {
+    pub(crate) base: Base,
+    pub(crate) diacritics: Vec<Diacritic>,
+}
+
+impl Letter {
+    fn plain(text: &str) -> Option<Self> {
+        Self::modified(text, &[])
+    }
+
+    fn modified(text: &str, diacritics: &[Diacritic]) -> Option<Self> {
+        let tie = Tie::Above.character();
+        let base = if text.contains(tie) {
+            Base::tied(text.chars().filter(|&ch| ch != tie), Tie::Above)
+        } else {
+            Base::sequence(text.chars())
+        };
+        Some(Self { base, diacritics: diacritics.to_vec() })
+    }
+
+    fn affricate(stop: Option<Self>, release: Option<Self>) -> Option<Self> {
+        let (mut stop, release) = stop.zip(release)?;
+        stop.base.characters.extend(release.base.characters);
+        stop.base.tie = Some(Tie::Above);
+        for diacritic in release.diacritics {
+            if !stop.diacritics.contains(&diacritic) {
+                stop.diacritics.push(diacritic);
+            }
+        }
+        Some(stop)
+    }
+
+    fn with(mut self, diacritic: Diacritic) -> Self {
+        self.diacritics.push(diacritic);
+        self
+    }
+}
+
+fn or_else<F>(letters: Letters, fallback: F) -> Letters
+where
+    F: FnOnce() -> Letters,
+{
+    match letters {
+        (None, None) => fallback(),
+        letters => letters,
+    }
+}
+
+fn with(letters: Letters, diacritic: Diacritic) -> Letters {
+    let (voiceless, voiced) = letters;
+    (
+        voiceless.map(|letter| letter.with(diacritic)),
+        voiced.map(|letter| letter.with(diacritic)),
+    )
+}
+
 impl Consonant {
-    pub fn grapheme_cluster(self) -> GraphemeCluster<Diacritic> {
-        let mut diacritics = Vec::new();
-        let character = match self.manner {
-            Manner::Plosive => match self.cavity {
-                Cavity::Oral => match (self.place, self.phonation) {
-                    (Place::Labial, Phonation::Voiceless) => 'p',
-                    (Place::Labial, Phonation::Voiced) => 'b',
-                    (Place::Alveolar, Phonation::Voiceless) => 't',
-                    (Place::Alveolar, Phonation::Voiced) => 'd',
-                    (Place::Velar, Phonation::Voiceless) => 'k',
-                    (Place::Velar, Phonation::Voiced) => 'g',
-                },
-                Cavity::Nasal => {
-                    match self.phonation {
-                        Phonation::Voiceless => {
-                            diacritics.push(Diacritic::Voiceless)
-                        },
-                        Phonation::Voiced => (),
-                    }
-                    match self.place {
-                        Place::Labial => 'm',
-                        Place::Alveolar => 'n',
-                        Place::Velar => 'ŋ',
-                    }
-                },
-            },
-            Manner::Fricative => {
-                match self.cavity {
-                    Cavity::Nasal => diacritics.push(Diacritic::Nasalized),
-                    Cavity::Oral => (),
-                }
-                match (self.place, self.phonation) {
-                    (Place::Labial, Phonation::Voiceless) => 'ɸ',
-                    (Place::Labial, Phonation::Voiced) => 'β',
-                    (Place::Alveolar, Phonation::Voiceless) => 's',
-                    (Place::Alveolar, Phonation::Voiced) => 'z',
-                    (Place::Velar, Phonation::Voiceless) => 'x',
-                    (Place::Velar, Phonation::Voiced) => 'ɣ',
-                }
-            },
-            Manner::Approximant => {
-                match self.phonation {
-                    Phonation::Voiced => (),
-                    Phonation::Voiceless => {
-                        diacritics.push(Diacritic::Voiceless)
-                    },
-                }
-                match self.cavity {
-                    Cavity::Nasal => diacritics.push(Diacritic::Nasalized),
-                    Cavity::Oral => (),
-                }
-                match self.place {
-                    Place::Labial => 'ʋ',
-                    Place::Alveolar => 'ɹ',
-                    Place::Velar => 'ɰ',
-                }
+    pub fn grapheme_cluster(self) -> Compound<Diacritic> {
+        self.grapheme_cluster_with([])
+    }
+
+    pub(crate) fn grapheme_cluster_with<I>(
+        self,
+        extra: I,
+    ) -> Compound<Diacritic>
+    where
+        I: IntoIterator<Item = Diacritic>,
+    {
+        let (base, diacritics) = self.marks(extra);
+        Compound::solve(
+            base.clone(),
+            diacritics.iter().map(|diacritic| (Attachment::Whole, *diacritic)),
+        )
+        .unwrap_or_else(|_| Compound {
+            members: vec![pos::TotalMap::default(); base.len()],
+            whole: unsolved(diacritics),
+            base,
+        })
+    }
+
+    pub fn try_grapheme_cluster(
+        self,
+    ) -> Result<Compound<Diacritic>, SolveError> {
+        let (base, diacritics) = self.marks([]);
+        Compound::solve(
+            base,
+            diacritics
+                .into_iter()
+                .map(|diacritic| (Attachment::Whole, diacritic)),
+        )
+    }
+
+    fn marks<I>(self, extra: I) -> (Base, Vec<Diacritic>)
+    where
+        I: IntoIterator<Item = Diacritic>,
+    {
+        let (voiceless, voiced) = self.letters();
+        let paired = voiceless.is_some() && voiced.is_some();
+        let (letter, voiced_letter) = match (voiceless, voiced) {
+            (Some(letter), None) => (letter, false),
+            (None, Some(letter)) => (letter, true),
+            (Some(letter), Some(_)) if !self.phonation.voiced_letter() => {
+                (letter, false)
             },
+            (Some(_), Some(letter)) => (letter, true),
+            (None, None) => unreachable!("every place has a letter"),
         };
-
+        let mut diacritics = letter.diacritics;
+        diacritics.extend(self.phonation.diacritics(voiced_letter, paired));
+        if self.airstream == Airstream::Ejective {
+            diacritics.push(Diacritic::Ejective);
+        }
+        for secondary in self.secondary.iter() {
+            let diacritic = secondary.diacritic();
+            if !diacritics.contains(&diacritic) {
+                diacritics.push(diacritic);
+            }
+        }
+        match (self.cavity, self.manner) {
+            (Cavity::Oral, Manner::Nasal) => {
+                diacritics.push(Diacritic::Denasal)
+            },
+            (Cavity::Nasal, Manner::Nasal) | (Cavity::Oral, _) => (),
+            (Cavity::Nasal, _) => diacritics.push(Diacritic::Nasalized),
+        }
         if self.syllabic {
             diacritics.push(Diacritic::Syllabic);
         }
+        diacritics.extend(extra);
+        (letter.base, diacritics)
+    }
 
-        let hints = slot::hints(character).unwrap();
-        GraphemeCluster::solve(character, hints, diacritics).unwrap()
+    pub(crate) fn letters(self) -> Letters {
+        match self.airstream {
+            Airstream::Pulmonic | Airstream::Ejective => {
+                self.pulmonic_letters()
+            },
+            Airstream::Implosive => or_else(self.implosive_letters(), || {
+                with(self.pulmonic_letters(), Diacritic::Implosive)
+            }),
+            Airstream::Click => or_else(self.click_letters(), || {
+                with(self.pulmonic_letters(), Diacritic::Click)
+            }),
+        }
+    }
+
+    fn pulmonic_letters(self) -> Letters {
+        match self.manner {
+            Manner::Plosive => self.plosive_letters(),
+            Manner::Nasal => or_else(self.nasal_letters(), || {
+                with(self.plosive_letters(), Diacritic::NasalStop)
+            }),
+            Manner::Trill => or_else(self.trill_letters(), || {
+                with(self.plosive_letters(), Diacritic::Trilled)
+            }),
+            Manner::Tap => self.tap_letters(),
+            Manner::SibilantAffricate => {
+                or_else(self.sibilant_affricate_letters(), || {
+                    with(self.affricate_letters(), Diacritic::Sibilant)
+                })
+            },
+            Manner::Affricate => self.affricate_letters(),
+            Manner::LateralAffricate => {
+                or_else(self.lateral_affricate_letters(), || {
+                    with(self.affricate_letters(), Diacritic::Lateral)
+                })
+            },
+            Manner::SibilantFricative => {
+                or_else(self.sibilant_letters(), || {
+                    with(self.fricative_letters(), Diacritic::Sibilant)
+                })
+            },
+            Manner::Fricative => self.fricative_letters(),
+            Manner::LateralFricative => {
+                or_else(self.lateral_fricative_letters(), || {
+                    with(self.fricative_letters(), Diacritic::Lateral)
+                })
+            },
+            Manner::Approximant => self.approximant_letters(),
+            Manner::LateralApproximant => {
+                or_else(self.lateral_approximant_letters(), || {
+                    with(self.approximant_letters(), Diacritic::Lateral)
+                })
+            },
+        }
+    }
+
+    fn implosive_letters(self) -> Letters {
+        if self.manner != Manner::Plosive {
+            return (None, None);
+        }
+        let voiced = match self.place {
+            Place::Bilabial => Letter::plain("ɓ"),
+            Place::Labiodental => Letter::modified("ɓ", &[Diacritic::Dental]),
+            Place::Dental => Letter::modified("ɗ", &[Diacritic::Dental]),
+            Place::Alveolar => Letter::plain("ɗ"),
+            Place::Postalveolar => {
+                Letter::modified("ɗ", &[Diacritic::Retracted])
+            },
+            Place::Retroflex => Letter::plain("ᶑ"),
+            Place::Palatal => Letter::plain("ʄ"),
+            Place::Velar => Letter::plain("ɠ"),
+            Place::Uvular => Letter::plain("ʛ"),
+            Place::Pharyngeal | Place::Glottal => None,
+            Place::LabialVelar => Letter::plain("ɠ\u{0361}ɓ"),
+        };
+        (None, voiced)
+    }
+
+    fn click_letters(self) -> Letters {
+        let click = match (self.manner, self.place) {
+            (Manner::LateralAffricate, Place::Alveolar) => "ǁ",
+            (Manner::LateralAffricate, _) => return (None, None),
+            (Manner::Plosive | Manner::Nasal, Place::Bilabial) => "ʘ",
+            (Manner::Plosive | Manner::Nasal, Place::Dental) => "ǀ",
+            (Manner::Plosive | Manner::Nasal, Place::Alveolar) => "ǃ",
+            (Manner::Plosive | Manner::Nasal, Place::Retroflex) => "‼",
+            (Manner::Plosive | Manner::Nasal, Place::Palatal) => "ǂ",
+            _ => return (None, None),
+        };
+        let accompanied =
+            |stop: &str| Letter::plain(&format!("{}\u{0361}{}", stop, click));
+        match self.manner {
+            Manner::Nasal => (None, accompanied("ŋ")),
+            _ => (Letter::plain(click), accompanied("g")),
+        }
+    }
+
+    fn plosive_letters(self) -> Letters {
+        match self.place {
+            Place::Bilabial => (Letter::plain("p"), Letter::plain("b")),
+            Place::Labiodental => (
+                Letter::modified("p", &[Diacritic::Dental]),
+                Letter::modified("b", &[Diacritic::Dental]),
+            ),
+            Place::Dental => (
+                Letter::modified("t", &[Diacritic::Dental]),
+                Letter::modified("d", &[Diacritic::Dental]),
+            ),
+            Place::Alveolar => (Letter::plain("t"), Letter::plain("d")),
+            Place::Postalveolar => (
+                Letter::modified("t", &[Diacritic::Retracted]),
+                Letter::modified("d", &[Diacritic::Retracted]),
+            ),
+            Place::Retroflex => (Letter::plain("ʈ"), Letter::plain("ɖ")),
+            Place::Palatal => (Letter::plain("c"), Letter::plain("ɟ")),
+            Place::Velar => (Letter::plain("k"), Letter::plain("g")),
+            Place::Uvular => (Letter::plain("q"), Letter::plain("ɢ")),
+            Place::Pharyngeal => (Letter::plain("ʡ"), None),
+            Place::Glottal => (Letter::plain("ʔ"), None),
+            Place::LabialVelar => {
+                (Letter::plain("k\u{0361}p"), Letter::plain("g\u{0361}b"))
+            },
+        }
+    }
+
+    fn nasal_letters(self) -> Letters {
+        let voiced = match self.place {
+            Place::Bilabial => Letter::plain("m"),
+            Place::Labiodental => Letter::plain("ɱ"),
+            Place::Dental => Letter::modified("n", &[Diacritic::Dental]),
+            Place::Alveolar => Letter::plain("n"),
+            Place::Postalveolar => {
+                Letter::modified("n", &[Diacritic::Retracted])
+            },
+            Place::Retroflex => Letter::plain("ɳ"),
+            Place::Palatal => Letter::plain("ɲ"),
+            Place::Velar => Letter::plain("ŋ"),
+            Place::Uvular => Letter::plain("ɴ"),
+            Place::Pharyngeal | Place::Glottal => None,
+            Place::LabialVelar => Letter::plain("ŋ\u{0361}m"),
+        };
+        (None, voiced)
+    }
+
+    fn trill_letters(self) -> Letters {
+        match self.place {
+            Place::Bilabial => (None, Letter::plain("ʙ")),
+            Place::Labiodental => {
+                (None, Letter::modified("ʙ", &[Diacritic::Dental]))
+            },
+            Place::Dental => {
+                (None, Letter::modified("r", &[Diacritic::Dental]))
+            },
+            Place::Alveolar => (None, Letter::plain("r")),
+            Place::Postalveolar => {
+                (None, Letter::modified("r", &[Diacritic::Retracted]))
+            },
+            Place::Retroflex => (None, Letter::plain("ɽ\u{0361}r")),
+            Place::Uvular => (None, Letter::plain("ʀ")),
+            Place::Pharyngeal => (Letter::plain("ʜ"), Letter::plain("ʢ")),
+            Place::Palatal
+            | Place::Velar
+            | Place::Glottal
+            | Place::LabialVelar => (None, None),
+        }
+    }
+
+    fn tap_letters(self) -> Letters {
+        let voiced = match self.place {
+            Place::Labiodental => Letter::plain("ⱱ"),
+            Place::Dental => Letter::modified("ɾ", &[Diacritic::Dental]),
+            Place::Alveolar => Letter::plain("ɾ"),
+            Place::Postalveolar => {
+                Letter::modified("ɾ", &[Diacritic::Retracted])
+            },
+            Place::Retroflex => Letter::plain("ɽ"),
+            _ => return with(self.plosive_letters(), Diacritic::ExtraShort),
+        };
+        (None, voiced)
+    }
+
+    fn affricate_letters(self) -> Letters {
+        let (stop_voiceless, stop_voiced) = self.plosive_letters();
+        let (voiceless, voiced) = self.fricative_letters();
+        (
+            Letter::affricate(stop_voiceless, voiceless),
+            Letter::affricate(stop_voiced, voiced),
+        )
+    }
+
+    fn sibilant_affricate_letters(self) -> Letters {
+        let (stop_voiceless, stop_voiced) = match self.place {
+            Place::Dental => (
+                Letter::modified("t", &[Diacritic::Dental]),
+                Letter::modified("d", &[Diacritic::Dental]),
+            ),
+            Place::Alveolar | Place::Postalveolar | Place::Palatal => {
+                (Letter::plain("t"), Letter::plain("d"))
+            },
+            Place::Retroflex => (Letter::plain("ʈ"), Letter::plain("ɖ")),
+            _ => return (None, None),
+        };
+        let (voiceless, voiced) = self.sibilant_letters();
+        (
+            Letter::affricate(stop_voiceless, voiceless),
+            Letter::affricate(stop_voiced, voiced),
+        )
+    }
+
+    fn lateral_affricate_letters(self) -> Letters {
+        let (stop_voiceless, stop_voiced) = self.plosive_letters();
+        let (voiceless, voiced) = self.lateral_fricative_letters();
+        (
+            Letter::affricate(stop_voiceless, voiceless),
+            Letter::affricate(stop_voiced, voiced),
+        )
+    }
+
+    fn sibilant_letters(self) -> Letters {
+        match self.place {
+            Place::Dental => (
+                Letter::modified("s", &[Diacritic::Dental]),
+                Letter::modified("z", &[Diacritic::Dental]),
+            ),
+            Place::Alveolar => (Letter::plain("s"), Letter::plain("z")),
+            Place::Postalveolar => (Letter::plain("ʃ"), Letter::plain("ʒ")),
+            Place::Retroflex => (Letter::plain("ʂ"), Letter::plain("ʐ")),
+            Place::Palatal => (Letter::plain("ɕ"), Letter::plain("ʑ")),
+            _ => (None, None),
+        }
+    }
+
+    fn fricative_letters(self) -> Letters {
+        match self.place {
+            Place::Bilabial => (Letter::plain("ɸ"), Letter::plain("β")),
+            Place::Labiodental => (Letter::plain("f"), Letter::plain("v")),
+            Place::Dental => (Letter::plain("θ"), Letter::plain("ð")),
+            Place::Alveolar => {
+                (None, Letter::modified("ɹ", &[Diacritic::Raised]))
+            },
+            Place::Postalveolar => (
+                None,
+                Letter::modified("ɹ", &[
+                    Diacritic::Retracted,
+                    Diacritic::Raised,
+                ]),
+            ),
+            Place::Retroflex => {
+                (None, Letter::modified("ɻ", &[Diacritic::Raised]))
+            },
+            Place::Palatal => (Letter::plain("ç"), Letter::plain("ʝ")),
+            Place::Velar => (Letter::plain("x"), Letter::plain("ɣ")),
+            Place::Uvular => (Letter::plain("χ"), Letter::plain("ʁ")),
+            Place::Pharyngeal => (Letter::plain("ħ"), Letter::plain("ʕ")),
+            Place::Glottal => (Letter::plain("h"), Letter::plain("ɦ")),
+            Place::LabialVelar => (Letter::plain("ʍ"), None),
+        }
+    }
+
+    fn lateral_fricative_letters(self) -> Letters {
+        match self.place {
+            Place::Dental => (
+                Letter::modified("ɬ", &[Diacritic::Dental]),
+                Letter::modified("ɮ", &[Diacritic::Dental]),
+            ),
+            Place::Alveolar => (Letter::plain("ɬ"), Letter::plain("ɮ")),
+            Place::Postalveolar => (
+                Letter::modified("ɬ", &[Diacritic::Retracted]),
+                Letter::modified("ɮ", &[Diacritic::Retracted]),
+            ),
+            Place::Retroflex => (
+                Letter::plain("\u{a78e}"),
+                Letter::modified("ɭ", &[Diacritic::Raised]),
+            ),
+            Place::Palatal => {
+                (None, Letter::modified("ʎ", &[Diacritic::Raised]))
+            },
+            Place::Velar => (None, Letter::modified("ʟ", &[Diacritic::Raised])),
+            _ => (None, None),
+        }
+    }
+
+    fn approximant_letters(self) -> Letters {
+        let voiced = match self.place {
+            Place::Bilabial => Letter::modified("β", &[Diacritic::Lowered]),
+            Place::Labiodental => Letter::plain("ʋ"),
+            Place::Dental => Letter::modified("ð", &[Diacritic::Lowered]),
+            Place::Alveolar => Letter::plain("ɹ"),
+            Place::Postalveolar => {
+                Letter::modified("ɹ", &[Diacritic::Retracted])
+            },
+            Place::Retroflex => Letter::plain("ɻ"),
+            Place::Palatal => Letter::plain("j"),
+            Place::Velar => Letter::plain("ɰ"),
+            Place::Uvular => Letter::modified("ʁ", &[Diacritic::Lowered]),
+            Place::Pharyngeal => Letter::modified("ʕ", &[Diacritic::Lowered]),
+            Place::Glottal => Letter::modified("ʔ", &[Diacritic::Lowered]),
+            Place::LabialVelar => Letter::plain("w"),
+        };
+        (None, voiced)
+    }
+
+    fn lateral_approximant_letters(self) -> Letters {
+        let voiced = match self.place {
+            Place::Dental => Letter::modified("l", &[Diacritic::Dental]),
+            Place::Alveolar => Letter::plain("l"),
+            Place::Postalveolar => {
+                Letter::modified("l", &[Diacritic::Retracted])
+            },
+            Place::Retroflex => Letter::plain("ɭ"),
+            Place::Palatal => Letter::plain("ʎ"),
+            Place::Velar => Letter::plain("ʟ"),
+            Place::Uvular => Letter::modified("ʟ", &[Diacritic::Retracted]),
+            _ => return (None, None),
+        };
+        (None, voiced)
     }
 }
 
@@ -132,17 +730,612 @@ impl fmt::Display for Consonant {
 
 #[cfg(test)]
 mod test {
-    use super::{Cavity, Consonant, Manner, Phonation, Place};
+    use super::{
+        Airstream,
+        Cavity,
+        Consonant,
+        Manner,
+        Phonation,
+        Place,
+        Secondaries,
+        Secondary,
+    };
+    use diakritikos::{base::Attachment, Compound};
 
     #[test]
     fn syllabic_voiceless_nasal_labial_approximant() {
         let consonant = Consonant {
-            place: Place::Labial,
+            place: Place::Labiodental,
             manner: Manner::Approximant,
+            airstream: Airstream::Pulmonic,
+            secondary: Secondaries::default(),
             cavity: Cavity::Nasal,
             phonation: Phonation::Voiceless,
             syllabic: true,
         };
         assert_eq!(consonant.to_string(), "ʋ̥̩̃");
     }
+
+    #[test]
+    fn plosive_phonations() {
+        let plosive = |phonation| Consonant {
+            place: Place::Bilabial,
+            manner: Manner::Plosive,
+            airstream: Airstream::Pulmonic,
+            secondary: Secondaries::default(),
+            cavity: Cavity::Oral,
+            phonation,
+            syllabic: false,
+        };
+        let rendered: Vec<_> = [
+            Phonation::Voiceless,
+            Phonation::Aspirated,
+            Phonation::Breathy,
+            Phonation::Slack,
+            Phonation::Voiced,
+            Phonation::Stiff,
+            Phonation::Creaky,
+        ]
+        .into_iter()
+        .map(|phonation| plosive(phonation).to_string())
+        .collect();
+        assert_eq!(rendered, [
+            "p", "pʰ", "b\u{0324}", "b\u{0325}", "b", "p\u{032c}", "b\u{0330}",
+        ]);
+    }
+
+    #[test]
+    fn nasal_phonations() {
+        let nasal = |phonation| Consonant {
+            place: Place::Bilabial,
+            manner: Manner::Nasal,
+            airstream: Airstream::Pulmonic,
+            secondary: Secondaries::default(),
+            cavity: Cavity::Nasal,
+            phonation,
+            syllabic: false,
+        };
+        let rendered: Vec<_> = Phonation::ALL
+            .into_iter()
+            .map(|phonation| nasal(phonation).to_string())
+            .collect();
+        assert_eq!(rendered, [
+            "m\u{0325}", "m\u{0325}ʰ", "m\u{0324}", "m\u{030a}\u{0324}", "m",
+            "m\u{032c}", "m\u{0330}",
+        ]);
+    }
+
+    #[test]
+    fn aspirated_nasal() {
+        let consonant = Consonant {
+            place: Place::Alveolar,
+            manner: Manner::Nasal,
+            airstream: Airstream::Pulmonic,
+            secondary: Secondaries::default(),
+            cavity: Cavity::Nasal,
+            phonation: Phonation::Aspirated,
+            syllabic: false,
+        };
+        assert_eq!(consonant.to_string(), "n\u{0325}ʰ");
+    }
+
+    #[test]
+    fn places_of_articulation() {
+        let consonant = |place, manner, phonation| Consonant {
+            place,
+            manner,
+            phonation,
+            airstream: Airstream::Pulmonic,
+            secondary: Secondaries::default(),
+            cavity: Cavity::Oral,
+            syllabic: false,
+        };
+        let cases = [
+            (Place::Labiodental, Manner::Fricative, Phonation::Voiced, "v"),
+            (
+                Place::Labiodental,
+                Manner::Plosive,
+                Phonation::Voiceless,
+                "p\u{032a}",
+            ),
+            (Place::Dental, Manner::Plosive, Phonation::Voiceless, "t\u{032a}"),
+            (Place::Dental, Manner::Fricative, Phonation::Voiced, "ð"),
+            (
+                Place::Postalveolar,
+                Manner::SibilantFricative,
+                Phonation::Voiceless,
+                "ʃ",
+            ),
+            (
+                Place::Postalveolar,
+                Manner::Plosive,
+                Phonation::Voiced,
+                "d\u{0320}",
+            ),
+            (Place::Retroflex, Manner::Plosive, Phonation::Voiced, "ɖ"),
+            (Place::Palatal, Manner::Approximant, Phonation::Voiced, "j"),
+            (Place::Uvular, Manner::Plosive, Phonation::Voiceless, "q"),
+            (Place::Pharyngeal, Manner::Fricative, Phonation::Voiced, "ʕ"),
+            (Place::Glottal, Manner::Plosive, Phonation::Voiceless, "ʔ"),
+            (
+                Place::Glottal,
+                Manner::Fricative,
+                Phonation::Breathy,
+                "ɦ\u{0324}",
+            ),
+            (Place::LabialVelar, Manner::Approximant, Phonation::Voiced, "w"),
+            (
+                Place::LabialVelar,
+                Manner::Approximant,
+                Phonation::Voiceless,
+                "w\u{0325}",
+            ),
+            (Place::LabialVelar, Manner::Fricative, Phonation::Voiceless, "ʍ"),
+            (
+                Place::LabialVelar,
+                Manner::Plosive,
+                Phonation::Voiceless,
+                "k\u{0361}p",
+            ),
+        ];
+        for (place, manner, phonation, expected) in cases {
+            assert_eq!(
+                consonant(place, manner, phonation).to_string(),
+                expected,
+                "{:?} {:?} {:?}",
+                place,
+                manner,
+                phonation
+            );
+        }
+    }
+
+    #[test]
+    fn nasal_fallbacks() {
+        let nasal = |place| Consonant {
+            place,
+            manner: Manner::Nasal,
+            phonation: Phonation::Voiced,
+            airstream: Airstream::Pulmonic,
+            secondary: Secondaries::default(),
+            cavity: Cavity::Nasal,
+            syllabic: false,
+        };
+        assert_eq!(nasal(Place::Labiodental).to_string(), "ɱ");
+        assert_eq!(nasal(Place::Dental).to_string(), "n\u{032a}");
+        assert_eq!(nasal(Place::Glottal).to_string(), "ʔ\u{032c}ᵑ");
+        assert_eq!(nasal(Place::LabialVelar).to_string(), "ŋ\u{0361}m");
+        let denasal =
+            Consonant { cavity: Cavity::Oral, ..nasal(Place::Bilabial) };
+        assert_eq!(denasal.to_string(), "m\u{034a}");
+    }
+
+    #[test]
+    fn manners_of_articulation() {
+        let consonant = |place, manner, phonation| Consonant {
+            place,
+            manner,
+            phonation,
+            airstream: Airstream::Pulmonic,
+            secondary: Secondaries::default(),
+            cavity: Cavity::Oral,
+            syllabic: false,
+        };
+        let cases = [
+            (Place::Alveolar, Manner::Trill, Phonation::Voiced, "r"),
+            (Place::Uvular, Manner::Trill, Phonation::Voiced, "ʀ"),
+            (Place::Labiodental, Manner::Trill, Phonation::Voiced, "ʙ\u{032a}"),
+            (Place::Velar, Manner::Trill, Phonation::Voiced, "gʳ"),
+            (Place::Alveolar, Manner::Tap, Phonation::Voiced, "ɾ"),
+            (Place::Bilabial, Manner::Tap, Phonation::Voiced, "b\u{0306}"),
+            (
+                Place::Postalveolar,
+                Manner::SibilantAffricate,
+                Phonation::Voiceless,
+                "t\u{0361}ʃ",
+            ),
+            (
+                Place::Palatal,
+                Manner::SibilantAffricate,
+                Phonation::Voiced,
+                "d\u{0361}ʑ",
+            ),
+            (
+                Place::Labiodental,
+                Manner::SibilantAffricate,
+                Phonation::Voiceless,
+                "p\u{0361}f\u{032a}ˢ",
+            ),
+            (
+                Place::Velar,
+                Manner::Affricate,
+                Phonation::Voiceless,
+                "k\u{0361}x",
+            ),
+            (
+                Place::Alveolar,
+                Manner::LateralAffricate,
+                Phonation::Voiceless,
+                "t\u{0361}ɬ",
+            ),
+            (
+                Place::Retroflex,
+                Manner::SibilantFricative,
+                Phonation::Voiced,
+                "ʐ",
+            ),
+            (
+                Place::Bilabial,
+                Manner::SibilantFricative,
+                Phonation::Voiceless,
+                "ɸˢ",
+            ),
+            (
+                Place::Alveolar,
+                Manner::Fricative,
+                Phonation::Voiced,
+                "ɹ\u{031d}",
+            ),
+            (Place::Alveolar, Manner::LateralFricative, Phonation::Voiced, "ɮ"),
+            (
+                Place::Velar,
+                Manner::LateralFricative,
+                Phonation::Voiceless,
+                "ʟ\u{0325}\u{02d4}",
+            ),
+            (
+                Place::Palatal,
+                Manner::LateralApproximant,
+                Phonation::Voiced,
+                "ʎ",
+            ),
+            (
+                Place::Bilabial,
+                Manner::LateralApproximant,
+                Phonation::Voiced,
+                "β\u{031e}ˡ",
+            ),
+        ];
+        for (place, manner, phonation, expected) in cases {
+            assert_eq!(
+                consonant(place, manner, phonation).to_string(),
+                expected,
+                "{:?} {:?} {:?}",
+                place,
+                manner,
+                phonation
+            );
+        }
+    }
+
+    #[test]
+    fn lenition_chains() {
+        let lenition = |mut manner: Manner| {
+            let mut chain = vec![manner];
+            while let Some(lenited) = manner.try_lenit() {
+                manner = lenited;
+                chain.push(manner);
+            }
+            chain
+        };
+        assert_eq!(lenition(Manner::Plosive), [
+            Manner::Plosive,
+            Manner::Affricate,
+            Manner::Fricative,
+            Manner::Approximant,
+        ]);
+        assert_eq!(lenition(Manner::Trill), [
+            Manner::Trill,
+            Manner::Tap,
+            Manner::Approximant,
+        ]);
+        assert_eq!(lenition(Manner::SibilantAffricate), [
+            Manner::SibilantAffricate,
+            Manner::SibilantFricative,
+            Manner::Approximant,
+        ]);
+        assert_eq!(lenition(Manner::LateralAffricate), [
+            Manner::LateralAffricate,
+            Manner::LateralFricative,
+            Manner::LateralApproximant,
+        ]);
+
+        let mut manner = Manner::Tap;
+        manner.fortify();
+        assert_eq!(manner, Manner::Trill);
+        manner.fortify();
+        assert_eq!(manner, Manner::Trill);
+        manner.lenit();
+        assert_eq!(manner, Manner::Tap);
+        assert_eq!(
+            Manner::LateralApproximant.try_fortify(),
+            Some(Manner::LateralFricative)
+        );
+        assert_eq!(Manner::Fricative.try_fortify(), Some(Manner::Affricate));
+        assert!(Manner::SibilantFricative.is_sibilant());
+        assert!(!Manner::LateralFricative.is_sibilant());
+        assert!(Manner::LateralAffricate.is_lateral());
+    }
+
+    #[test]
+    fn airstream_mechanisms() {
+        let consonant = |place, manner, airstream, phonation| Consonant {
+            place,
+            manner,
+            airstream,
+            secondary: Secondaries::default(),
+            phonation,
+            cavity: match manner {
+                Manner::Nasal => Cavity::Nasal,
+                _ => Cavity::Oral,
+            },
+            syllabic: false,
+        };
+        let cases = [
+            (
+                Place::Velar,
+                Manner::Plosive,
+                Airstream::Ejective,
+                Phonation::Voiceless,
+                "kʼ",
+            ),
+            (
+                Place::Alveolar,
+                Manner::SibilantFricative,
+                Airstream::Ejective,
+                Phonation::Voiceless,
+                "sʼ",
+            ),
+            (
+                Place::Bilabial,
+                Manner::Plosive,
+                Airstream::Implosive,
+                Phonation::Voiced,
+                "ɓ",
+            ),
+            (
+                Place::Palatal,
+                Manner::Plosive,
+                Airstream::Implosive,
+                Phonation::Voiceless,
+                "ʄ\u{0325}",
+            ),
+            (
+                Place::Glottal,
+                Manner::Plosive,
+                Airstream::Implosive,
+                Phonation::Voiceless,
+                "ʔ↓",
+            ),
+            (
+                Place::Alveolar,
+                Manner::Nasal,
+                Airstream::Implosive,
+                Phonation::Voiced,
+                "n↓",
+            ),
+            (
+                Place::Bilabial,
+                Manner::Plosive,
+                Airstream::Click,
+                Phonation::Voiceless,
+                "ʘ",
+            ),
+            (
+                Place::Dental,
+                Manner::Plosive,
+                Airstream::Click,
+                Phonation::Voiceless,
+                "ǀ",
+            ),
+            (
+                Place::Alveolar,
+                Manner::Plosive,
+                Airstream::Click,
+                Phonation::Voiced,
+                "g\u{0361}ǃ",
+            ),
+            (
+                Place::Palatal,
+                Manner::Nasal,
+                Airstream::Click,
+                Phonation::Voiced,
+                "ŋ\u{0361}ǂ",
+            ),
+            (
+                Place::Alveolar,
+                Manner::LateralAffricate,
+                Airstream::Click,
+                Phonation::Voiceless,
+                "ǁ",
+            ),
+            (
+                Place::Velar,
+                Manner::Plosive,
+                Airstream::Click,
+                Phonation::Voiceless,
+                "k\u{a71d}",
+            ),
+            (
+                Place::Postalveolar,
+                Manner::Plosive,
+                Airstream::Click,
+                Phonation::Voiceless,
+                "t\u{0320}\u{a71d}",
+            ),
+            (
+                Place::Alveolar,
+                Manner::SibilantAffricate,
+                Airstream::Click,
+                Phonation::Voiceless,
+                "t\u{0361}s\u{a71d}",
+            ),
+        ];
+        for (place, manner, airstream, phonation, expected) in cases {
+            assert_eq!(
+                consonant(place, manner, airstream, phonation).to_string(),
+                expected,
+                "{:?} {:?} {:?} {:?}",
+                place,
+                manner,
+                airstream,
+                phonation
+            );
+        }
+    }
+
+    #[test]
+    fn secondary_articulations() {
+        let consonant = |place, manner, phonation, secondary| Consonant {
+            place,
+            manner,
+            airstream: Airstream::Pulmonic,
+            secondary,
+            phonation,
+            cavity: Cavity::Oral,
+            syllabic: false,
+        };
+        let cases = [
+            (
+                Place::Velar,
+                Manner::Plosive,
+                Phonation::Voiceless,
+                Secondaries::from([Secondary::Labialized]),
+                "kʷ",
+            ),
+            (
+                Place::Alveolar,
+                Manner::Plosive,
+                Phonation::Voiced,
+                Secondaries::from([Secondary::Prenasalized]),
+                "ⁿd",
+            ),
+            (
+                Place::Alveolar,
+                Manner::Plosive,
+                Phonation::Voiceless,
+                Secondaries::from([Secondary::Preaspirated]),
+                "ʰt",
+            ),
+            (
+                Place::Alveolar,
+                Manner::SibilantFricative,
+                Phonation::Voiceless,
+                Secondaries::from([Secondary::Pharyngealized]),
+                "sˤ",
+            ),
+            (
+                Place::Velar,
+                Manner::Plosive,
+                Phonation::Aspirated,
+                Secondaries::from([
+                    Secondary::Postaspirated,
+                    Secondary::Labialized,
+                ]),
+                "k\u{032b}ʰ",
+            ),
+            (
+                Place::Bilabial,
+                Manner::Plosive,
+                Phonation::Voiceless,
+                Secondaries::from([
+                    Secondary::Labialized,
+                    Secondary::Palatalized,
+                    Secondary::Postaspirated,
+                ]),
+                "p\u{032b}ʲʰ",
+            ),
+            (
+                Place::Alveolar,
+                Manner::LateralApproximant,
+                Phonation::Voiced,
+                Secondaries::from([
+                    Secondary::Palatalized,
+                    Secondary::Velarized,
+                    Secondary::Postaspirated,
+                ]),
+                "lʲˠʰ",
+            ),
+        ];
+        for (place, manner, phonation, secondary, expected) in cases {
+            assert_eq!(
+                consonant(place, manner, phonation, secondary).to_string(),
+                expected,
+                "{:?} {:?} {:?} {:?}",
+                place,
+                manner,
+                phonation,
+                secondary
+            );
+        }
+    }
+
+    #[test]
+    fn airstreams_with_secondaries() {
+        for airstream in Airstream::ALL {
+            for bits in 0 .. 1u8 << Secondary::ALL.len() {
+                let secondary = Secondary::ALL
+                    .into_iter()
+                    .enumerate()
+                    .filter(|(index, _)| bits & 1 << index != 0)
+                    .map(|(_, secondary)| secondary)
+                    .collect();
+                let consonant = Consonant {
+                    place: Place::Alveolar,
+                    manner: Manner::Plosive,
+                    airstream,
+                    secondary,
+                    phonation: Phonation::Voiceless,
+                    cavity: Cavity::Oral,
+                    syllabic: false,
+                };
+                let compound = consonant.grapheme_cluster();
+                assert_eq!(
+                    compound,
+                    Compound::solve(
+                        compound.base.clone(),
+                        compound.whole.iter().flat_map(|(_, slot)| {
+                            slot.diacritics.iter().map(|diacritic| {
+                                (Attachment::Whole, *diacritic)
+                            })
+                        })
+                    )
+                    .unwrap(),
+                    "{:?} {:?}",
+                    airstream,
+                    secondary
+                );
+            }
+        }
+        let consonant = Consonant {
+            place: Place::Alveolar,
+            manner: Manner::Plosive,
+            airstream: Airstream::Ejective,
+            secondary: Secondaries::from([
+                Secondary::Palatalized,
+                Secondary::Postaspirated,
+            ]),
+            phonation: Phonation::Voiceless,
+            cavity: Cavity::Oral,
+            syllabic: false,
+        };
+        assert_eq!(consonant.to_string(), "tʼʲʰ");
+    }
+
+    #[test]
+    fn secondaries_set() {
+        let mut secondaries = Secondaries::new();
+        assert!(secondaries.is_empty());
+        assert!(secondaries.insert(Secondary::Velarized));
+        assert!(secondaries.insert(Secondary::Labialized));
+        assert!(!secondaries.insert(Secondary::Velarized));
+        assert_eq!(secondaries.len(), 2);
+        assert_eq!(secondaries.iter().collect::<Vec<_>>(), [
+            Secondary::Labialized,
+            Secondary::Velarized,
+        ]);
+        assert!(secondaries.remove(Secondary::Labialized));
+        assert!(!secondaries.contains(Secondary::Labialized));
+        assert_eq!(secondaries, Secondaries::from([Secondary::Velarized]));
+    }
 }
@@ -1,4 +1,4 @@
-use diakritikos::{pos, Position};
+use diakritikos::{parse::Variants, pos, Position};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub enum Diacritic {
@@ -6,10 +6,47 @@ pub enum Diacritic {
     Lowered,
     Voiced,
     Voiceless,
+    Aspirated,
+    Ejective,
+    Implosive,
+    Click,
+    Trilled,
+    Sibilant,
+    Lateral,
+    NasalStop,
+    Denasal,
+    Breathy,
+    Creaky,
     Centralized,
+    Dental,
+    Retracted,
+    Advanced,
+    Raised,
+    ExtraShort,
     NonSyllabic,
     Syllabic,
     Labialized,
+    Palatalized,
+    Velarized,
+    Pharyngealized,
+    Prenasalized,
+    Preaspirated,
+    HalfLong,
+    Long,
+    Overlong,
+    ExtraHighTone,
+    HighTone,
+    MidTone,
+    LowTone,
+    ExtraLowTone,
+    RisingTone,
+    FallingTone,
+    HighRisingTone,
+    LowRisingTone,
+    HighFallingTone,
+    LowFallingTone,
+    PeakingTone,
+    DippingTone,
 }
 
 impl diakritikos::Diacritic for Diacritic {
@@ -29,9 +66,61 @@ impl diakritikos::Diacritic for Diacritic {
                 (Position::Bottom, "\u{0325}"),
                 (Position::Top, "\u{030a}"),
             ]),
+            Diacritic::Aspirated => {
+                pos::PartialMap::from_iter([(Position::Right, "ʰ")])
+            },
+            Diacritic::Ejective => {
+                pos::PartialMap::from_iter([(Position::Right, "ʼ")])
+            },
+            Diacritic::Implosive => {
+                pos::PartialMap::from_iter([(Position::Right, "↓")])
+            },
+            Diacritic::Click => {
+                pos::PartialMap::from_iter([(Position::Right, "\u{a71d}")])
+            },
+            Diacritic::Trilled => {
+                pos::PartialMap::from_iter([(Position::Right, "ʳ")])
+            },
+            Diacritic::Sibilant => {
+                pos::PartialMap::from_iter([(Position::Right, "ˢ")])
+            },
+            Diacritic::Lateral => {
+                pos::PartialMap::from_iter([(Position::Right, "ˡ")])
+            },
+            Diacritic::NasalStop => {
+                pos::PartialMap::from_iter([(Position::Right, "ᵑ")])
+            },
+            Diacritic::Denasal => {
+                pos::PartialMap::from_iter([(Position::Top, "\u{034a}")])
+            },
+            Diacritic::Breathy => {
+                pos::PartialMap::from_iter([(Position::Bottom, "\u{0324}")])
+            },
+            Diacritic::Creaky => {
+                pos::PartialMap::from_iter([(Position::Bottom, "\u{0330}")])
+            },
             Diacritic::Centralized => {
                 pos::PartialMap::from_iter([(Position::Top, "\u{0308}")])
             },
+            Diacritic::Dental => pos::PartialMap::from_iter([
+                (Position::Bottom, "\u{032a}"),
+                (Position::Top, "\u{0346}"),
+            ]),
+            Diacritic::Retracted => pos::PartialMap::from_iter([
+                (Position::Bottom, "\u{0320}"),
+                (Position::Right, "\u{02d7}"),
+            ]),
+            Diacritic::Advanced => pos::PartialMap::from_iter([
+                (Position::Bottom, "\u{031f}"),
+                (Position::Right, "\u{02d6}"),
+            ]),
+            Diacritic::Raised => pos::PartialMap::from_iter([
+                (Position::Bottom, "\u{031d}"),
+                (Position::Right, "\u{02d4}"),
+            ]),
+            Diacritic::ExtraShort => {
+                pos::PartialMap::from_iter([(Position::Top, "\u{0306}")])
+            },
             Diacritic::NonSyllabic => pos::PartialMap::from_iter([
                 (Position::Bottom, "\u{032f}"),
                 (Position::Top, "\u{0311}"),
@@ -40,9 +129,131 @@ impl diakritikos::Diacritic for Diacritic {
                 (Position::Bottom, "\u{0329}"),
                 (Position::Top, "\u{030d}"),
             ]),
-            Diacritic::Labialized => {
-                pos::PartialMap::from_iter([(Position::Right, "ʷ")])
+            Diacritic::Labialized => pos::PartialMap::from_iter([
+                (Position::Right, "ʷ"),
+                (Position::Bottom, "\u{032b}"),
+            ]),
+            Diacritic::Palatalized => {
+                pos::PartialMap::from_iter([(Position::Right, "ʲ")])
+            },
+            Diacritic::Velarized => pos::PartialMap::from_iter([
+                (Position::Right, "ˠ"),
+                (Position::Through, "\u{0334}"),
+            ]),
+            Diacritic::Pharyngealized => pos::PartialMap::from_iter([
+                (Position::Right, "ˤ"),
+                (Position::Through, "\u{0334}"),
+            ]),
+            Diacritic::Prenasalized => {
+                pos::PartialMap::from_iter([(Position::Left, "ⁿ")])
+            },
+            Diacritic::Preaspirated => {
+                pos::PartialMap::from_iter([(Position::Left, "ʰ")])
+            },
+            Diacritic::HalfLong => {
+                pos::PartialMap::from_iter([(Position::Right, "ˑ")])
+            },
+            Diacritic::Long => {
+                pos::PartialMap::from_iter([(Position::Right, "ː")])
+            },
+            Diacritic::Overlong => {
+                pos::PartialMap::from_iter([(Position::Right, "ːː")])
+            },
+            Diacritic::ExtraHighTone => {
+                pos::PartialMap::from_iter([(Position::Top, "\u{030b}")])
+            },
+            Diacritic::HighTone => {
+                pos::PartialMap::from_iter([(Position::Top, "\u{0301}")])
+            },
+            Diacritic::MidTone => {
+                pos::PartialMap::from_iter([(Position::Top, "\u{0304}")])
+            },
+            Diacritic::LowTone => {
+                pos::PartialMap::from_iter([(Position::Top, "\u{0300}")])
+            },
+            Diacritic::ExtraLowTone => {
+                pos::PartialMap::from_iter([(Position::Top, "\u{030f}")])
+            },
+            Diacritic::RisingTone => {
+                pos::PartialMap::from_iter([(Position::Top, "\u{030c}")])
+            },
+            Diacritic::FallingTone => {
+                pos::PartialMap::from_iter([(Position::Top, "\u{0302}")])
+            },
+            Diacritic::HighRisingTone => {
+                pos::PartialMap::from_iter([(Position::Top, "\u{1dc4}")])
+            },
+            Diacritic::LowRisingTone => {
+                pos::PartialMap::from_iter([(Position::Top, "\u{1dc5}")])
+            },
+            Diacritic::HighFallingTone => {
+                pos::PartialMap::from_iter([(Position::Top, "\u{1dc7}")])
+            },
+            Diacritic::LowFallingTone => {
+                pos::PartialMap::from_iter([(Position::Top, "\u{1dc6}")])
+            },
+            Diacritic::PeakingTone => {
+                pos::PartialMap::from_iter([(Position::Top, "\u{1dc8}")])
+            },
+            Diacritic::DippingTone => {
+                pos::PartialMap::from_iter([(Position::Top, "\u{1dc9}")])
             },
         }
     }
+
+    // The right slot keeps the default unlimited capacity: the ejective
+    // mark, aspiration and every secondary articulation stack there, so a
+    // cap of two would leave phones such as tʼʲʰ without a rendering.
+}
+
+impl Variants for Diacritic {
+    fn variants() -> Vec<Self> {
+        vec![
+            Diacritic::Nasalized,
+            Diacritic::Lowered,
+            Diacritic::Voiced,
+            Diacritic::Voiceless,
+            Diacritic::Aspirated,
+            Diacritic::Ejective,
+            Diacritic::Implosive,
+            Diacritic::Click,
+            Diacritic::Trilled,
+            Diacritic::Sibilant,
+            Diacritic::Lateral,
+            Diacritic::NasalStop,
+            Diacritic::Denasal,
+            Diacritic::Breathy,
+            Diacritic::Creaky,
+            Diacritic::Centralized,
+            Diacritic::Dental,
+            Diacritic::Retracted,
+            Diacritic::Advanced,
+            Diacritic::Raised,
+            Diacritic::ExtraShort,
+            Diacritic::NonSyllabic,
+            Diacritic::Syllabic,
+            Diacritic::Labialized,
+            Diacritic::Palatalized,
+            Diacritic::Velarized,
+            Diacritic::Pharyngealized,
+            Diacritic::Prenasalized,
+            Diacritic::Preaspirated,
+            Diacritic::HalfLong,
+            Diacritic::Long,
+            Diacritic::Overlong,
+            Diacritic::ExtraHighTone,
+            Diacritic::HighTone,
+            Diacritic::MidTone,
+            Diacritic::LowTone,
+            Diacritic::ExtraLowTone,
+            Diacritic::RisingTone,
+            Diacritic::FallingTone,
+            Diacritic::HighRisingTone,
+            Diacritic::LowRisingTone,
+            Diacritic::HighFallingTone,
+            Diacritic::LowFallingTone,
+            Diacritic::PeakingTone,
+            Diacritic::DippingTone,
+        ]
+    }
 }
@@ -0,0 +1,329 @@
+use crate::{
+    feature::{Feature, Features},
+    Phone,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Weights {
+    features: [f64; Feature::ALL.len()],
+    unspecified: f64,
+    insertion: f64,
+    deletion: f64,
+}
+
+impl Default for Weights {
+    fn default() -> Self {
+        Self {
+            features: [1.0; Feature::ALL.len()],
+            unspecified: 0.5,
+            insertion: 4.0,
+            deletion: 4.0,
+        }
+    }
+}
+
+impl Weights {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn feature(&self, feature: Feature) -> f64 {
+        self.features[feature as usize]
+    }
+
+    pub fn set_feature(&mut self, feature: Feature, weight: f64) {
+        self.features[feature as usize] = checked(weight);
+    }
+
+    pub fn with_feature(mut self, feature: Feature, weight: f64) -> Self {
+        self.set_feature(feature, weight);
+        self
+    }
+
+    pub fn unspecified(&self) -> f64 {
+        self.unspecified
+    }
+
+    pub fn set_unspecified(&mut self, weight: f64) {
+        self.unspecified = checked(weight);
+    }
+
+    pub fn with_unspecified(mut self, weight: f64) -> Self {
+        self.set_unspecified(weight);
+        self
+    }
+
+    pub fn insertion(&self) -> f64 {
+        self.insertion
+    }
+
+    pub fn set_insertion(&mut self, weight: f64) {
+        self.insertion = checked(weight);
+    }
+
+    pub fn with_insertion(mut self, weight: f64) -> Self {
+        self.set_insertion(weight);
+        self
+    }
+
+    pub fn deletion(&self) -> f64 {
+        self.deletion
+    }
+
+    pub fn set_deletion(&mut self, weight: f64) {
+        self.deletion = checked(weight);
+    }
+
+    pub fn with_deletion(mut self, weight: f64) -> Self {
+        self.set_deletion(weight);
+        self
+    }
+
+    pub fn features_distance(&self, left: Features, right: Features) -> f64 {
+        Feature::ALL
+            .into_iter()
+            .map(|feature| match (left.get(feature), right.get(feature)) {
+                (Some(left), Some(right)) if left == right => 0.0,
+                (None, None) => 0.0,
+                (Some(_), Some(_)) => self.feature(feature),
+                (Some(_), None) | (None, Some(_)) => {
+                    self.feature(feature) * self.unspecified
+                },
+            })
+            .sum()
+    }
+
+    pub fn distance(&self, left: Phone, right: Phone) -> f64 {
+        self.features_distance(left.features(), right.features())
+    }
+
+    pub fn align(&self, source: &[Phone], target: &[Phone]) -> Alignment {
+        let columns = target.len() + 1;
+        let mut costs = vec![0.0; (source.len() + 1) * columns];
+        let mut moves = vec![Move::Substitute; (source.len() + 1) * columns];
+        for i in 1 ..= source.len() {
+            costs[i * columns] = costs[(i - 1) * columns] + self.deletion;
+            moves[i * columns] = Move::Delete;
+        }
+        for j in 1 ..= target.len() {
+            costs[j] = costs[j - 1] + self.insertion;
+            moves[j] = Move::Insert;
+        }
+        for i in 1 ..= source.len() {
+            for j in 1 ..= target.len() {
+                let substitution = costs[(i - 1) * columns + j - 1]
+                    + self.distance(source[i - 1], target[j - 1]);
+                let deletion = costs[(i - 1) * columns + j] + self.deletion;
+                let insertion = costs[i * columns + j - 1] + self.insertion;
+                let (cost, chosen) =
+                    if substitution <= deletion && substitution <= insertion {
+                        (substitution, Move::Substitute)
+                    } else if deletion <= insertion {
+                        (deletion, Move::Delete)
+                    } else {
+                        (insertion, Move::Insert)
+                    };
+                costs[i * columns + j] = cost;
+                moves[i * columns + j] = chosen;
+            }
+        }
+
+        let mut steps = Vec::new();
+        let (mut i, mut j) = (source.len(), target.len());
+        while i > 0 || j > 0 {
+            match moves[i * columns + j] {
+                Move::Substitute => {
+                    i -= 1;
+                    j -= 1;
+                    steps.push(Step::Substitute { source: i, target: j });
+                },
+                Move::Delete => {
+                    i -= 1;
+                    steps.push(Step::Delete { source: i });
+                },
+                Move::Insert => {
+                    j -= 1;
+                    steps.push(Step::Insert { target: j });
+                },
+            }
+        }
+        steps.reverse();
+
+        Alignment {
+            steps,
+            distance: costs[source.len() * columns + target.len()],
+        }
+    }
+
+    pub fn sequence_distance(&self, source: &[Phone], target: &[Phone]) -> f64 {
+        self.align(source, target).distance
+    }
+}
+
+fn checked(weight: f64) -> f64 {
+    assert!(
+        weight.is_finite() && weight >= 0.0,
+        "weight {} must be finite and non-negative",
+        weight
+    );
+    weight
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Move {
+    Substitute,
+    Delete,
+    Insert,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Step {
+    Substitute { source: usize, target: usize },
+    Delete { source: usize },
+    Insert { target: usize },
+}
+
+impl Step {
+    pub fn source(self) -> Option<usize> {
+        match self {
+            Self::Substitute { source, .. } | Self::Delete { source } => {
+                Some(source)
+            },
+            Self::Insert { .. } => None,
+        }
+    }
+
+    pub fn target(self) -> Option<usize> {
+        match self {
+            Self::Substitute { target, .. } | Self::Insert { target } => {
+                Some(target)
+            },
+            Self::Delete { .. } => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Alignment {
+    pub steps: Vec<Step>,
+    pub distance: f64,
+}
+
+impl Alignment {
+    pub fn pairs(
+        &self,
+        source: &[Phone],
+        target: &[Phone],
+    ) -> Vec<(Option<Phone>, Option<Phone>)> {
+        self.steps
+            .iter()
+            .map(|step| {
+                (
+                    step.source().map(|index| source[index]),
+                    step.target().map(|index| target[index]),
+                )
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{Step, Weights};
+    use crate::{feature::Feature, parse, Phone};
+
+    fn phone(text: &str) -> Phone {
+        text.parse().unwrap()
+    }
+
+    #[test]
+    fn phone_distances() {
+        let weights = Weights::new();
+        let p = phone("p");
+        let b = phone("b");
+        let m = phone("m");
+        let a = phone("a");
+        assert_eq!(weights.distance(p, p), 0.0);
+        assert_eq!(weights.distance(p, b), 1.0);
+        assert_eq!(weights.distance(p, b), weights.distance(b, p));
+        assert!(weights.distance(p, b) < weights.distance(p, m));
+        assert!(weights.distance(p, m) < weights.distance(p, a));
+
+        let weights = Weights::new().with_feature(Feature::Voice, 3.0);
+        assert_eq!(weights.feature(Feature::Voice), 3.0);
+        assert_eq!(weights.distance(p, b), 3.0);
+    }
+
+    #[test]
+    fn sequence_alignment() {
+        let weights = Weights::new();
+        let source = parse("kat").unwrap();
+        let target = parse("kad").unwrap();
+        let alignment = weights.align(&source, &target);
+        assert_eq!(alignment.distance, 1.0);
+        assert_eq!(alignment.steps, [
+            Step::Substitute { source: 0, target: 0 },
+            Step::Substitute { source: 1, target: 1 },
+            Step::Substitute { source: 2, target: 2 },
+        ]);
+
+        let source = parse("akta").unwrap();
+        let target = parse("ata").unwrap();
+        let alignment = weights.align(&source, &target);
+        assert_eq!(alignment.distance, weights.deletion());
+        assert_eq!(alignment.steps, [
+            Step::Substitute { source: 0, target: 0 },
+            Step::Delete { source: 1 },
+            Step::Substitute { source: 2, target: 1 },
+            Step::Substitute { source: 3, target: 2 },
+        ]);
+        let pairs = alignment.pairs(&source, &target);
+        assert_eq!(pairs[1], (Some(phone("k")), None));
+        assert_eq!(pairs[2], (Some(phone("t")), Some(phone("t"))));
+
+        let alignment = weights.align(&target, &source);
+        assert_eq!(alignment.distance, weights.insertion());
+        assert_eq!(alignment.steps[1], Step::Insert { target: 1 });
+    }
+
+    #[test]
+    fn empty_sequences() {
+        let weights = Weights::new();
+        let word = parse("ab").unwrap();
+        assert_eq!(weights.sequence_distance(&[], &[]), 0.0);
+        assert_eq!(
+            weights.sequence_distance(&word, &[]),
+            2.0 * weights.deletion()
+        );
+        assert_eq!(weights.align(&[], &word).steps, [
+            Step::Insert { target: 0 },
+            Step::Insert { target: 1 },
+        ]);
+    }
+
+    #[test]
+    fn free_edits() {
+        let weights = Weights::new().with_insertion(0.0).with_deletion(0.0);
+        let source = parse("kat").unwrap();
+        let target = parse("a").unwrap();
+        let alignment = weights.align(&source, &target);
+        assert_eq!(alignment.distance, 0.0);
+        assert_eq!(alignment.steps, [
+            Step::Delete { source: 0 },
+            Step::Substitute { source: 1, target: 0 },
+            Step::Delete { source: 2 },
+        ]);
+    }
+
+    #[test]
+    #[should_panic(expected = "must be finite and non-negative")]
+    fn negative_weight() {
+        Weights::new().with_deletion(-1.0);
+    }
+
+    #[test]
+    #[should_panic(expected = "must be finite and non-negative")]
+    fn nan_weight() {
+        Weights::new().with_feature(Feature::Voice, f64::NAN);
+    }
+}
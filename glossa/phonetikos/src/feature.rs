@@ -0,0 +1,790 @@
+use crate::{
+    consonant::{Airstream, Consonant, Manner, Place, Secondaries, Secondary},
+    vowel::{Frontness, Height, Roundedness, Vowel},
+    Cavity,
+    Phonation,
+    Phone,
+};
+use std::sync::OnceLock;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Feature {
+    Syllabic,
+    Consonantal,
+    Sonorant,
+    Approximant,
+    Continuant,
+    DelayedRelease,
+    Nasal,
+    Lateral,
+    Strident,
+    Tap,
+    Trill,
+    Voice,
+    SpreadGlottis,
+    ConstrictedGlottis,
+    Stiff,
+    Slack,
+    RaisedLarynx,
+    LoweredLarynx,
+    Velaric,
+    Labial,
+    Round,
+    Labiodental,
+    Coronal,
+    Anterior,
+    Distributed,
+    Dorsal,
+    High,
+    Low,
+    Front,
+    Back,
+    Tense,
+    Pharyngeal,
+}
+
+impl Feature {
+    pub const ALL: [Self; 32] = [
+        Self::Syllabic,
+        Self::Consonantal,
+        Self::Sonorant,
+        Self::Approximant,
+        Self::Continuant,
+        Self::DelayedRelease,
+        Self::Nasal,
+        Self::Lateral,
+        Self::Strident,
+        Self::Tap,
+        Self::Trill,
+        Self::Voice,
+        Self::SpreadGlottis,
+        Self::ConstrictedGlottis,
+        Self::Stiff,
+        Self::Slack,
+        Self::RaisedLarynx,
+        Self::LoweredLarynx,
+        Self::Velaric,
+        Self::Labial,
+        Self::Round,
+        Self::Labiodental,
+        Self::Coronal,
+        Self::Anterior,
+        Self::Distributed,
+        Self::Dorsal,
+        Self::High,
+        Self::Low,
+        Self::Front,
+        Self::Back,
+        Self::Tense,
+        Self::Pharyngeal,
+    ];
+
+    fn bit(self) -> u64 {
+        1 << self as u64
+    }
+
+    fn secondaries(self) -> &'static [Secondary] {
+        match self {
+            Self::Labial | Self::Round => &[Secondary::Labialized],
+            Self::High | Self::Low | Self::Front | Self::Back => {
+                &[Secondary::Palatalized, Secondary::Velarized]
+            },
+            Self::Pharyngeal => &[Secondary::Pharyngealized],
+            Self::Nasal => &[Secondary::Prenasalized],
+            Self::SpreadGlottis => {
+                &[Secondary::Preaspirated, Secondary::Postaspirated]
+            },
+            _ => &[],
+        }
+    }
+
+    fn depends_on_secondaries(self) -> bool {
+        self == Self::Labiodental || !self.secondaries().is_empty()
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Features {
+    specified: u64,
+    positive: u64,
+}
+
+impl Features {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn is_empty(self) -> bool {
+        self.specified == 0
+    }
+
+    pub fn len(self) -> usize {
+        self.specified.count_ones() as usize
+    }
+
+    pub fn get(self, feature: Feature) -> Option<bool> {
+        (self.specified & feature.bit() != 0)
+            .then_some(self.positive & feature.bit() != 0)
+    }
+
+    pub fn set(&mut self, feature: Feature, value: bool) {
+        self.specified |= feature.bit();
+        if value {
+            self.positive |= feature.bit();
+        } else {
+            self.positive &= !feature.bit();
+        }
+    }
+
+    pub fn unset(&mut self, feature: Feature) {
+        self.specified &= !feature.bit();
+        self.positive &= !feature.bit();
+    }
+
+    pub fn with(mut self, feature: Feature, value: bool) -> Self {
+        self.set(feature, value);
+        self
+    }
+
+    pub fn iter(self) -> impl Iterator<Item = (Feature, bool)> {
+        Feature::ALL
+            .into_iter()
+            .filter_map(move |feature| Some((feature, self.get(feature)?)))
+    }
+
+    pub fn matches(self, class: Self) -> bool {
+        self.positive & class.specified == class.positive
+            && self.specified & class.specified == class.specified
+    }
+
+    pub fn overlay(self, modification: Self) -> Self {
+        Self {
+            specified: self.specified | modification.specified,
+            positive: self.positive & !modification.specified
+                | modification.positive,
+        }
+    }
+
+    pub fn distance(self, other: Self) -> usize {
+        let specified = self.specified ^ other.specified;
+        let positive =
+            (self.positive ^ other.positive) & self.specified & other.specified;
+        (specified | positive).count_ones() as usize
+    }
+
+    fn restrict(self, mask: u64) -> Self {
+        Self {
+            specified: self.specified & mask,
+            positive: self.positive & mask,
+        }
+    }
+
+    fn set_place(&mut self, high: bool, low: bool, front: bool, back: bool) {
+        self.set(Feature::High, high);
+        self.set(Feature::Low, low);
+        self.set(Feature::Front, front);
+        self.set(Feature::Back, back);
+    }
+}
+
+impl FromIterator<(Feature, bool)> for Features {
+    fn from_iter<I>(iter: I) -> Self
+    where
+        I: IntoIterator<Item = (Feature, bool)>,
+    {
+        let mut features = Self::new();
+        for (feature, value) in iter {
+            features.set(feature, value);
+        }
+        features
+    }
+}
+
+impl<const N: usize> From<[(Feature, bool); N]> for Features {
+    fn from(features: [(Feature, bool); N]) -> Self {
+        features.into_iter().collect()
+    }
+}
+
+fn laryngeal(features: &mut Features, phonation: Phonation) {
+    features.set(Feature::Voice, phonation.is_voiced());
+    features.set(
+        Feature::SpreadGlottis,
+        matches!(phonation, Phonation::Aspirated | Phonation::Breathy),
+    );
+    features.set(Feature::ConstrictedGlottis, phonation == Phonation::Creaky);
+    features.set(Feature::Stiff, phonation == Phonation::Stiff);
+    features.set(Feature::Slack, phonation == Phonation::Slack);
+    features.set(Feature::RaisedLarynx, false);
+    features.set(Feature::LoweredLarynx, false);
+    features.set(Feature::Velaric, false);
+}
+
+impl Consonant {
+    pub fn features(self) -> Features {
+        let mut features = Features::new();
+        let manner = self.manner;
+        let glide = manner == Manner::Approximant
+            && !matches!(
+                self.place,
+                Place::Dental
+                    | Place::Alveolar
+                    | Place::Postalveolar
+                    | Place::Retroflex
+            );
+        features.set(Feature::Syllabic, self.syllabic);
+        features
+            .set(Feature::Consonantal, !glide && self.place != Place::Glottal);
+        features.set(
+            Feature::Sonorant,
+            matches!(
+                manner,
+                Manner::Nasal
+                    | Manner::Trill
+                    | Manner::Tap
+                    | Manner::Approximant
+                    | Manner::LateralApproximant
+            ),
+        );
+        features.set(
+            Feature::Approximant,
+            matches!(
+                manner,
+                Manner::Trill
+                    | Manner::Tap
+                    | Manner::Approximant
+                    | Manner::LateralApproximant
+            ),
+        );
+        features.set(
+            Feature::Continuant,
+            matches!(
+                manner,
+                Manner::Trill
+                    | Manner::Tap
+                    | Manner::SibilantFricative
+                    | Manner::Fricative
+                    | Manner::LateralFricative
+                    | Manner::Approximant
+                    | Manner::LateralApproximant
+            ),
+        );
+        features.set(
+            Feature::DelayedRelease,
+            matches!(
+                manner,
+                Manner::SibilantAffricate
+                    | Manner::Affricate
+                    | Manner::LateralAffricate
+                    | Manner::SibilantFricative
+                    | Manner::Fricative
+                    | Manner::LateralFricative
+            ),
+        );
+        features.set(
+            Feature::Nasal,
+            self.cavity == Cavity::Nasal
+                || self.secondary.contains(Secondary::Prenasalized),
+        );
+        features.set(Feature::Lateral, manner.is_lateral());
+        features.set(
+            Feature::Strident,
+            manner.is_sibilant()
+                || matches!(self.place, Place::Labiodental | Place::Uvular)
+                    && matches!(manner, Manner::Affricate | Manner::Fricative),
+        );
+        features.set(Feature::Tap, manner == Manner::Tap);
+        features.set(Feature::Trill, manner == Manner::Trill);
+
+        laryngeal(&mut features, self.phonation);
+        if self.secondary.contains(Secondary::Preaspirated)
+            || self.secondary.contains(Secondary::Postaspirated)
+        {
+            features.set(Feature::SpreadGlottis, true);
+        }
+        match self.airstream {
+            Airstream::Pulmonic => (),
+            Airstream::Ejective => {
+                features.set(Feature::ConstrictedGlottis, true);
+                features.set(Feature::RaisedLarynx, true);
+            },
+            Airstream::Implosive => {
+                features.set(Feature::ConstrictedGlottis, true);
+                features.set(Feature::LoweredLarynx, true);
+            },
+            Airstream::Click => features.set(Feature::Velaric, true),
+        }
+
+        let labial = matches!(
+            self.place,
+            Place::Bilabial | Place::Labiodental | Place::LabialVelar
+        ) || self.secondary.contains(Secondary::Labialized);
+        features.set(Feature::Labial, labial);
+        if labial {
+            features.set(
+                Feature::Round,
+                self.place == Place::LabialVelar
+                    || self.secondary.contains(Secondary::Labialized),
+            );
+            features
+                .set(Feature::Labiodental, self.place == Place::Labiodental);
+        }
+
+        let coronal = matches!(
+            self.place,
+            Place::Dental
+                | Place::Alveolar
+                | Place::Postalveolar
+                | Place::Retroflex
+        );
+        features.set(Feature::Coronal, coronal);
+        if coronal {
+            features.set(
+                Feature::Anterior,
+                matches!(self.place, Place::Dental | Place::Alveolar),
+            );
+            features.set(
+                Feature::Distributed,
+                matches!(self.place, Place::Dental | Place::Postalveolar),
+            );
+        }
+
+        features.set(
+            Feature::Dorsal,
+            matches!(
+                self.place,
+                Place::Palatal
+                    | Place::Velar
+                    | Place::Uvular
+                    | Place::LabialVelar
+            ),
+        );
+        match self.place {
+            Place::Palatal => features.set_place(true, false, true, false),
+            Place::Velar | Place::LabialVelar => {
+                features.set_place(true, false, false, true)
+            },
+            Place::Uvular => features.set_place(false, false, false, true),
+            _ => (),
+        }
+        if self.secondary.contains(Secondary::Palatalized) {
+            features.set_place(true, false, true, false);
+        }
+        if self.secondary.contains(Secondary::Velarized) {
+            features.set_place(true, false, false, true);
+        }
+
+        features.set(
+            Feature::Pharyngeal,
+            self.place == Place::Pharyngeal
+                || self.secondary.contains(Secondary::Pharyngealized),
+        );
+        features
+    }
+}
+
+impl Vowel {
+    pub fn features(self) -> Features {
+        let mut features = Features::new();
+        features.set(Feature::Syllabic, self.syllabic);
+        features.set(Feature::Consonantal, false);
+        features.set(Feature::Sonorant, true);
+        features.set(Feature::Approximant, true);
+        features.set(Feature::Continuant, true);
+        features.set(Feature::DelayedRelease, false);
+        features.set(Feature::Nasal, self.cavity == Cavity::Nasal);
+        features.set(Feature::Lateral, false);
+        features.set(Feature::Strident, false);
+        features.set(Feature::Tap, false);
+        features.set(Feature::Trill, false);
+        laryngeal(&mut features, self.phonation);
+
+        let round = self.roundedness == Roundedness::Rounded;
+        features.set(Feature::Labial, round);
+        if round {
+            features.set(Feature::Round, true);
+            features.set(Feature::Labiodental, false);
+        }
+        features.set(Feature::Coronal, false);
+        features.set(Feature::Dorsal, true);
+        features.set_place(
+            matches!(self.height, Height::Close | Height::NearClose),
+            matches!(self.height, Height::Open | Height::NearOpen),
+            matches!(self.frontness, Frontness::Front | Frontness::NearFront),
+            matches!(self.frontness, Frontness::Back | Frontness::NearBack),
+        );
+        match self.height {
+            Height::Close | Height::CloseMid => {
+                features.set(Feature::Tense, true)
+            },
+            Height::NearClose | Height::OpenMid | Height::NearOpen => {
+                features.set(Feature::Tense, false)
+            },
+            Height::Mid | Height::Open => (),
+        }
+        features.set(Feature::Pharyngeal, false);
+        features
+    }
+}
+
+impl Phone {
+    pub fn features(self) -> Features {
+        match self {
+            Phone::Vowel(vowel) => vowel.features(),
+            Phone::Consonant(consonant) => consonant.features(),
+        }
+    }
+
+    pub fn is_in(self, class: Features) -> bool {
+        self.features().matches(class)
+    }
+
+    pub fn with_features(self, modification: Features) -> Option<Self> {
+        let target = self.features().overlay(modification);
+        let mut best: Option<(usize, usize, usize, Self)> = None;
+        let consider = |best: &mut Option<(usize, usize, usize, Self)>,
+                        phone: Self,
+                        features: Features,
+                        rendered: bool| {
+            if !features.matches(modification) {
+                return;
+            }
+            let key = (
+                target.distance(features),
+                field_distance(self, phone),
+                markedness(phone),
+                phone,
+            );
+            if best.is_none_or(|best| key < best)
+                && (rendered || phone.try_render().is_ok())
+            {
+                *best = Some(key);
+            }
+        };
+        for &(phone, features) in inventory() {
+            consider(&mut best, phone, features, true);
+        }
+
+        let mut secondaries = match self {
+            Phone::Consonant(consonant) => consonant.secondary,
+            Phone::Vowel(_) => Secondaries::new(),
+        };
+        for (feature, _) in modification.iter() {
+            secondaries.extend(feature.secondaries().iter().copied());
+        }
+        let secondaries: Vec<_> = secondaries.iter().collect();
+        // Secondaries only touch a few features, so the rest of a bundle
+        // bounds every variant of the phone from below.
+        let fixed = !Feature::ALL
+            .into_iter()
+            .filter(|feature| feature.depends_on_secondaries())
+            .fold(0, |mask, feature| mask | feature.bit());
+        for &(phone, features) in inventory() {
+            let Phone::Consonant(consonant) = phone else {
+                continue;
+            };
+            if !features.restrict(fixed).matches(modification.restrict(fixed)) {
+                continue;
+            }
+            let bound =
+                target.restrict(fixed).distance(features.restrict(fixed));
+            if best.is_some_and(|(distance, ..)| bound > distance) {
+                continue;
+            }
+            for subset in 1 .. 1_u32 << secondaries.len() {
+                let secondary = secondaries
+                    .iter()
+                    .enumerate()
+                    .filter(|(index, _)| subset & 1 << index != 0)
+                    .map(|(_, secondary)| *secondary)
+                    .collect();
+                let phone =
+                    Phone::Consonant(Consonant { secondary, ..consonant });
+                consider(&mut best, phone, phone.features(), false);
+            }
+        }
+
+        best.map(|(.., phone)| phone)
+    }
+}
+
+fn inventory() -> &'static [(Phone, Features)] {
+    static INVENTORY: OnceLock<Vec<(Phone, Features)>> = OnceLock::new();
+    INVENTORY.get_or_init(|| {
+        let mut phones = Vec::new();
+        for height in Height::ALL {
+            for frontness in Frontness::ALL {
+                for roundedness in Roundedness::ALL {
+                    for_each_common(|phonation, cavity, syllabic| {
+                        phones.push(Phone::Vowel(Vowel {
+                            height,
+                            frontness,
+                            roundedness,
+                            phonation,
+                            cavity,
+                            syllabic,
+                        }))
+                    });
+                }
+            }
+        }
+        for place in Place::ALL {
+            for manner in Manner::ALL {
+                for airstream in Airstream::ALL {
+                    for_each_common(|phonation, cavity, syllabic| {
+                        phones.push(Phone::Consonant(Consonant {
+                            place,
+                            manner,
+                            airstream,
+                            secondary: Secondaries::new(),
+                            phonation,
+                            cavity,
+                            syllabic,
+                        }))
+                    });
+                }
+            }
+        }
+        phones
+            .into_iter()
+            .filter(|phone| phone.try_render().is_ok())
+            .map(|phone| (phone, phone.features()))
+            .collect()
+    })
+}
+
+fn for_each_common<F>(mut visit: F)
+where
+    F: FnMut(Phonation, Cavity, bool),
+{
+    for phonation in Phonation::ALL {
+        for cavity in [Cavity::Oral, Cavity::Nasal] {
+            for syllabic in [false, true] {
+                visit(phonation, cavity, syllabic);
+            }
+        }
+    }
+}
+
+fn markedness(phone: Phone) -> usize {
+    match phone {
+        Phone::Vowel(vowel) => {
+            usize::from(matches!(
+                vowel.height,
+                Height::NearOpen | Height::Mid | Height::NearClose
+            )) + usize::from(matches!(
+                vowel.frontness,
+                Frontness::NearFront | Frontness::NearBack
+            ))
+        },
+        Phone::Consonant(_) => 0,
+    }
+}
+
+fn field_distance(left: Phone, right: Phone) -> usize {
+    let common = usize::from(left.phonation() != right.phonation())
+        + usize::from(left.cavity() != right.cavity())
+        + usize::from(left.syllabic() != right.syllabic());
+    let specific = match (left, right) {
+        (Phone::Consonant(left), Phone::Consonant(right)) => {
+            usize::from(left.place != right.place)
+                + usize::from(left.manner != right.manner)
+                + usize::from(left.airstream != right.airstream)
+                + Secondary::ALL
+                    .into_iter()
+                    .filter(|secondary| {
+                        left.secondary.contains(*secondary)
+                            != right.secondary.contains(*secondary)
+                    })
+                    .count()
+        },
+        (Phone::Vowel(left), Phone::Vowel(right)) => {
+            usize::from(left.height != right.height)
+                + usize::from(left.frontness != right.frontness)
+                + usize::from(left.roundedness != right.roundedness)
+        },
+        _ => Feature::ALL.len(),
+    };
+    common + specific
+}
+
+#[cfg(test)]
+mod test {
+    use super::{Feature, Features};
+    use crate::{
+        consonant::{Airstream, Consonant, Manner, Place, Secondaries},
+        vowel::{Frontness, Height, Roundedness, Vowel},
+        Cavity,
+        Phonation,
+        Phone,
+    };
+
+    fn consonant(place: Place, manner: Manner, phonation: Phonation) -> Phone {
+        Phone::Consonant(Consonant {
+            place,
+            manner,
+            airstream: Airstream::Pulmonic,
+            secondary: Secondaries::new(),
+            phonation,
+            cavity: match manner {
+                Manner::Nasal => Cavity::Nasal,
+                _ => Cavity::Oral,
+            },
+            syllabic: false,
+        })
+    }
+
+    fn vowel(
+        height: Height,
+        frontness: Frontness,
+        roundedness: Roundedness,
+    ) -> Phone {
+        Phone::Vowel(Vowel {
+            height,
+            frontness,
+            roundedness,
+            phonation: Phonation::Voiced,
+            cavity: Cavity::Oral,
+            syllabic: true,
+        })
+    }
+
+    #[test]
+    fn feature_bundles() {
+        let features = Features::new()
+            .with(Feature::Voice, true)
+            .with(Feature::Nasal, false);
+        assert_eq!(features.len(), 2);
+        assert_eq!(features.get(Feature::Voice), Some(true));
+        assert_eq!(features.get(Feature::Nasal), Some(false));
+        assert_eq!(features.get(Feature::Lateral), None);
+        assert_eq!(
+            features.overlay(Features::from([(Feature::Voice, false)])),
+            Features::from([(Feature::Voice, false), (Feature::Nasal, false)])
+        );
+        assert_eq!(
+            features.distance(Features::from([
+                (Feature::Voice, false),
+                (Feature::Lateral, true),
+            ])),
+            3
+        );
+
+        let b = consonant(Place::Bilabial, Manner::Plosive, Phonation::Voiced)
+            .features();
+        assert_eq!(b.get(Feature::Voice), Some(true));
+        assert_eq!(b.get(Feature::Continuant), Some(false));
+        assert_eq!(b.get(Feature::Labial), Some(true));
+        assert_eq!(b.get(Feature::Round), Some(false));
+        assert_eq!(b.get(Feature::Coronal), Some(false));
+        assert_eq!(b.get(Feature::Anterior), None);
+
+        let i = vowel(Height::Close, Frontness::Front, Roundedness::Unrounded)
+            .features();
+        assert_eq!(i.get(Feature::Syllabic), Some(true));
+        assert_eq!(i.get(Feature::High), Some(true));
+        assert_eq!(i.get(Feature::Back), Some(false));
+        assert_eq!(i.get(Feature::Round), None);
+    }
+
+    #[test]
+    fn natural_classes() {
+        let phones = [
+            consonant(Place::Bilabial, Manner::Plosive, Phonation::Voiceless),
+            consonant(Place::Bilabial, Manner::Plosive, Phonation::Voiced),
+            consonant(Place::Bilabial, Manner::Nasal, Phonation::Voiced),
+            consonant(
+                Place::Alveolar,
+                Manner::SibilantFricative,
+                Phonation::Voiced,
+            ),
+            consonant(
+                Place::Alveolar,
+                Manner::LateralApproximant,
+                Phonation::Voiced,
+            ),
+            consonant(Place::Velar, Manner::Plosive, Phonation::Voiced),
+            vowel(Height::Close, Frontness::Back, Roundedness::Rounded),
+        ];
+        let members = |class: Features| -> Vec<String> {
+            phones
+                .iter()
+                .filter(|phone| phone.is_in(class))
+                .map(|phone| phone.to_string())
+                .collect()
+        };
+        assert_eq!(
+            members(Features::from([
+                (Feature::Voice, true),
+                (Feature::Sonorant, false),
+            ])),
+            ["b", "z", "g"]
+        );
+        assert_eq!(
+            members(Features::from([
+                (Feature::Sonorant, true),
+                (Feature::Consonantal, true),
+            ])),
+            ["m", "l"]
+        );
+        assert_eq!(members(Features::from([(Feature::Labial, true)])), [
+            "p", "b", "m", "u"
+        ]);
+        assert_eq!(members(Features::from([(Feature::Anterior, true)])), [
+            "z", "l"
+        ]);
+    }
+
+    #[test]
+    fn closest_phones() {
+        let t =
+            consonant(Place::Alveolar, Manner::Plosive, Phonation::Voiceless);
+        let k = consonant(Place::Velar, Manner::Plosive, Phonation::Voiceless);
+        let b = consonant(Place::Bilabial, Manner::Plosive, Phonation::Voiced);
+        let i = vowel(Height::Close, Frontness::Front, Roundedness::Unrounded);
+        let a = vowel(Height::Open, Frontness::Front, Roundedness::Unrounded);
+        let cases = [
+            (t, Features::from([(Feature::Voice, true)]), "d"),
+            (t, Features::from([(Feature::Anterior, false)]), "ʈ"),
+            (k, Features::from([(Feature::Continuant, true)]), "x"),
+            (k, Features::from([(Feature::Round, true)]), "kʷ"),
+            (
+                t,
+                Features::from([(Feature::High, true), (Feature::Front, true)]),
+                "tʲ",
+            ),
+            (b, Features::from([(Feature::Nasal, true)]), "b\u{0303}"),
+            (
+                b,
+                Features::from([
+                    (Feature::Nasal, true),
+                    (Feature::Sonorant, true),
+                ]),
+                "m",
+            ),
+            (i, Features::from([(Feature::Round, true)]), "y"),
+            (i, Features::from([(Feature::High, false)]), "e"),
+            (i, Features::from([(Feature::Syllabic, false)]), "i\u{032f}"),
+            (a, Features::from([(Feature::Back, true)]), "ɑ"),
+            (t, Features::new(), "t"),
+        ];
+        for (phone, modification, expected) in cases {
+            assert_eq!(
+                phone
+                    .with_features(modification)
+                    .map(|phone| phone.try_render().unwrap()),
+                Some(String::from(expected)),
+                "{} {:?}",
+                phone,
+                modification
+            );
+        }
+        assert_eq!(
+            a.with_features(Features::from([
+                (Feature::High, true),
+                (Feature::Low, true),
+            ])),
+            None
+        );
+    }
+}
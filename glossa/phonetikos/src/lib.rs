@@ -1,15 +1,80 @@
 pub mod diacritic;
 pub mod vowel;
 pub mod consonant;
+pub mod suprasegmental;
+pub mod parse;
+pub mod feature;
+pub mod distance;
 
 use consonant::Consonant;
-use std::fmt;
+use diacritic::Diacritic;
+use diakritikos::{pos, Diacritic as _, Slot, SolveError};
+use parse::{ParseError, Parser};
+use std::{fmt, str::FromStr};
 use vowel::Vowel;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub enum Phonation {
     Voiceless,
+    Aspirated,
+    Breathy,
+    Slack,
     Voiced,
+    Stiff,
+    Creaky,
+}
+
+impl Phonation {
+    pub const ALL: [Self; 7] = [
+        Self::Voiceless,
+        Self::Aspirated,
+        Self::Breathy,
+        Self::Slack,
+        Self::Voiced,
+        Self::Stiff,
+        Self::Creaky,
+    ];
+
+    pub fn is_voiced(self) -> bool {
+        match self {
+            Self::Voiceless | Self::Aspirated => false,
+            Self::Breathy
+            | Self::Slack
+            | Self::Voiced
+            | Self::Stiff
+            | Self::Creaky => true,
+        }
+    }
+
+    pub(crate) fn voiced_letter(self) -> bool {
+        !matches!(self, Self::Voiceless | Self::Aspirated | Self::Stiff)
+    }
+
+    pub(crate) fn diacritics(
+        self,
+        voiced_letter: bool,
+        paired: bool,
+    ) -> Vec<Diacritic> {
+        match (self, voiced_letter) {
+            (Self::Voiceless, false) | (Self::Voiced, true) => Vec::new(),
+            (Self::Voiceless, true) => vec![Diacritic::Voiceless],
+            (Self::Aspirated, false) => vec![Diacritic::Aspirated],
+            (Self::Aspirated, true) => {
+                vec![Diacritic::Voiceless, Diacritic::Aspirated]
+            },
+            (Self::Breathy, _) => vec![Diacritic::Breathy],
+            (Self::Slack, true) if paired => vec![Diacritic::Voiceless],
+            (Self::Slack, true) => {
+                vec![Diacritic::Voiceless, Diacritic::Breathy]
+            },
+            (Self::Slack, false) => vec![Diacritic::Voiced, Diacritic::Breathy],
+            (Self::Voiced, false) => vec![Diacritic::Voiced],
+            (Self::Stiff, false) if paired => vec![Diacritic::Voiced],
+            (Self::Stiff, true) => vec![Diacritic::Voiced],
+            (Self::Stiff, false) => vec![Diacritic::Voiced, Diacritic::Creaky],
+            (Self::Creaky, _) => vec![Diacritic::Creaky],
+        }
+    }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
@@ -45,6 +110,51 @@ impl Phone {
             Phone::Consonant(consonant) => consonant.phonation,
         }
     }
+
+    pub(crate) fn render_with<I>(self, extra: I) -> String
+    where
+        I: IntoIterator<Item = Diacritic>,
+    {
+        match self {
+            Phone::Vowel(vowel) => {
+                vowel.grapheme_cluster_with(extra).to_string()
+            },
+            Phone::Consonant(consonant) => {
+                consonant.grapheme_cluster_with(extra).to_string()
+            },
+        }
+    }
+
+    pub fn try_render(self) -> Result<String, SolveError> {
+        match self {
+            Phone::Vowel(vowel) => {
+                Ok(vowel.try_grapheme_cluster()?.to_string())
+            },
+            Phone::Consonant(consonant) => {
+                Ok(consonant.try_grapheme_cluster()?.to_string())
+            },
+        }
+    }
+}
+
+pub(crate) fn unsolved(
+    diacritics: Vec<Diacritic>,
+) -> pos::TotalMap<Slot<Diacritic>> {
+    let mut slots = pos::TotalMap::<Slot<Diacritic>>::default();
+    for diacritic in diacritics {
+        if let Some((position, _)) = diacritic.renderings().iter().next() {
+            slots[position].diacritics.push(diacritic);
+        }
+    }
+    slots
+}
+
+impl FromStr for Phone {
+    type Err = ParseError;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        Parser::builtin().parse_phone(text)
+    }
 }
 
 impl fmt::Display for Phone {
@@ -55,3 +165,7 @@ impl fmt::Display for Phone {
         }
     }
 }
+
+pub fn parse(text: &str) -> Result<Vec<Phone>, ParseError> {
+    Parser::builtin().parse(text)
+}
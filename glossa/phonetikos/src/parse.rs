@@ -0,0 +1,813 @@
+use crate::{
+    consonant::{Airstream, Consonant, Manner, Place, Secondaries, Secondary},
+    diacritic::Diacritic,
+    suprasegmental::{Level, Stress},
+    vowel::{self, Frontness, Height, Roundedness, Vowel},
+    Cavity,
+    Phonation,
+    Phone,
+};
+use diakritikos::{base::Tie, parse::Variants, Diacritic as _, Position};
+use std::{
+    cmp::Reverse,
+    collections::{HashMap, HashSet},
+    error::Error,
+    fmt,
+    iter,
+    sync::OnceLock,
+};
+use unicode_normalization::UnicodeNormalization;
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ParseError {
+    UnknownSymbol { phone: usize, symbol: char },
+    MisplacedMark { phone: usize, mark: char },
+    InvalidPhone { phone: usize, text: String },
+    NotSinglePhone { count: usize },
+    Suprasegmental { phone: usize, mark: char },
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, fmtr: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::UnknownSymbol { phone, symbol } => write!(
+                fmtr,
+                "unknown symbol U+{:04X} in phone {}",
+                u32::from(*symbol),
+                phone
+            ),
+            Self::MisplacedMark { phone, mark } => write!(
+                fmtr,
+                "mark U+{:04X} in phone {} has no letter to attach to",
+                u32::from(*mark),
+                phone
+            ),
+            Self::InvalidPhone { phone, text } => {
+                write!(fmtr, "phone {} {:?} has no known reading", phone, text)
+            },
+            Self::NotSinglePhone { count } => {
+                write!(fmtr, "expected a single phone, found {}", count)
+            },
+            Self::Suprasegmental { phone, mark } => write!(
+                fmtr,
+                "suprasegmental mark U+{:04X} at phone {} is not supported, \
+                 only segmental phones can be parsed",
+                u32::from(*mark),
+                phone
+            ),
+        }
+    }
+}
+
+impl Error for ParseError {}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Reading {
+    Consonant {
+        place: Place,
+        manner: Manner,
+        airstream: Airstream,
+        voiced_letter: bool,
+        exclusive: bool,
+    },
+    Vowel {
+        height: Height,
+        frontness: Frontness,
+        roundedness: Roundedness,
+    },
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Candidate {
+    diacritics: Vec<Diacritic>,
+    reading: Reading,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+struct Token {
+    text: String,
+    letters: Vec<char>,
+    marks: Vec<(char, bool)>,
+}
+
+#[derive(Debug, Clone)]
+pub struct Parser {
+    candidates: HashMap<Vec<char>, Vec<Candidate>>,
+    letters: HashSet<char>,
+    prefixes: HashMap<char, Vec<Diacritic>>,
+    suffixes: HashMap<char, Vec<Diacritic>>,
+    suprasegmentals: HashSet<char>,
+}
+
+impl Default for Parser {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Parser {
+    pub fn new() -> Self {
+        let mut parser = Self {
+            candidates: HashMap::new(),
+            letters: HashSet::new(),
+            prefixes: HashMap::new(),
+            suffixes: HashMap::new(),
+            suprasegmentals: HashSet::new(),
+        };
+        for airstream in
+            [Airstream::Pulmonic, Airstream::Implosive, Airstream::Click]
+        {
+            for place in Place::ALL {
+                for manner in Manner::ALL {
+                    parser.register_consonant(place, manner, airstream);
+                }
+            }
+        }
+        for height in Height::ALL {
+            for frontness in Frontness::ALL {
+                for roundedness in Roundedness::ALL {
+                    let mut diacritics = Vec::new();
+                    let letter = vowel::letter(
+                        height, frontness, roundedness, &mut diacritics,
+                    );
+                    parser.register(vec![letter], Candidate {
+                        diacritics,
+                        reading: Reading::Vowel {
+                            height,
+                            frontness,
+                            roundedness,
+                        },
+                    });
+                }
+            }
+        }
+        for diacritic in Diacritic::variants() {
+            for (position, rendering) in &diacritic.renderings() {
+                let mut chars = rendering.nfd();
+                let Some(mark) = chars.next() else {
+                    continue;
+                };
+                if is_suprasegmental(diacritic) {
+                    parser.suprasegmentals.insert(mark);
+                    continue;
+                }
+                if chars.next().is_some() {
+                    continue;
+                }
+                let marks = match position {
+                    Position::Left => &mut parser.prefixes,
+                    _ => &mut parser.suffixes,
+                };
+                marks.entry(mark).or_default().push(diacritic);
+            }
+        }
+        parser.suprasegmentals.extend(Level::ALL.map(Level::letter));
+        parser.suprasegmentals.extend(
+            [Stress::Primary, Stress::Secondary]
+                .into_iter()
+                .flat_map(|stress| stress.mark().chars()),
+        );
+        parser
+    }
+
+    pub fn builtin() -> &'static Self {
+        static PARSER: OnceLock<Parser> = OnceLock::new();
+        PARSER.get_or_init(Self::new)
+    }
+
+    pub fn parse(&self, text: &str) -> Result<Vec<Phone>, ParseError> {
+        self.tokenize(text)?
+            .into_iter()
+            .enumerate()
+            .map(|(index, token)| self.parse_token(index, token))
+            .collect()
+    }
+
+    pub fn parse_phone(&self, text: &str) -> Result<Phone, ParseError> {
+        let phones = self.parse(text)?;
+        match phones[..] {
+            [phone] => Ok(phone),
+            _ => Err(ParseError::NotSinglePhone { count: phones.len() }),
+        }
+    }
+
+    fn register_consonant(
+        &mut self,
+        place: Place,
+        manner: Manner,
+        airstream: Airstream,
+    ) {
+        let consonant = Consonant {
+            place,
+            manner,
+            airstream,
+            secondary: Secondaries::new(),
+            phonation: Phonation::Voiced,
+            cavity: Cavity::Oral,
+            syllabic: false,
+        };
+        let (voiceless, voiced) = consonant.letters();
+        let exclusive = voiceless.is_some() && voiced.is_some();
+        for (letter, voiced_letter) in [(voiceless, false), (voiced, true)] {
+            if let Some(letter) = letter {
+                self.register(letter.base.characters, Candidate {
+                    diacritics: letter.diacritics,
+                    reading: Reading::Consonant {
+                        place,
+                        manner,
+                        airstream,
+                        voiced_letter,
+                        exclusive,
+                    },
+                });
+            }
+        }
+    }
+
+    fn register(&mut self, letters: Vec<char>, candidate: Candidate) {
+        self.letters.extend(letters.iter().copied());
+        let candidates = self.candidates.entry(letters).or_default();
+        if !candidates.contains(&candidate) {
+            candidates.push(candidate);
+        }
+    }
+
+    fn tokenize(&self, text: &str) -> Result<Vec<Token>, ParseError> {
+        let ties = [Tie::Above.character(), Tie::Below.character()];
+        let mut tokens = Vec::new();
+        let mut current: Option<Token> = None;
+        let mut prefix = Token::default();
+        let mut tied = false;
+        let characters: Vec<char> = text
+            .nfc()
+            .flat_map(|character| {
+                if self.letters.contains(&character) {
+                    vec![character]
+                } else {
+                    iter::once(character).nfd().collect()
+                }
+            })
+            .collect();
+        for character in characters {
+            let phone = tokens.len();
+            if tied {
+                let token = current.as_mut().unwrap();
+                if !self.letters.contains(&character) {
+                    return Err(ParseError::MisplacedMark {
+                        phone,
+                        mark: ties[0],
+                    });
+                }
+                token.letters.push(character);
+                token.text.push(character);
+                tied = false;
+            } else if ties.contains(&character) {
+                let Some(token) = current.as_mut() else {
+                    return Err(ParseError::MisplacedMark {
+                        phone,
+                        mark: character,
+                    });
+                };
+                token.text.push(character);
+                tied = true;
+            } else if self.letters.contains(&character) {
+                tokens.extend(current.take());
+                let mut token = std::mem::take(&mut prefix);
+                token.letters.push(character);
+                token.text.push(character);
+                current = Some(token);
+            } else if character.is_whitespace() {
+                if let Some(&(mark, _)) = prefix.marks.first() {
+                    return Err(ParseError::MisplacedMark {
+                        phone: phone + usize::from(current.is_some()),
+                        mark,
+                    });
+                }
+                tokens.extend(current.take());
+            } else if self.suprasegmentals.contains(&character) {
+                let stress = Stress::Primary.mark().contains(character)
+                    || Stress::Secondary.mark().contains(character);
+                return Err(ParseError::Suprasegmental {
+                    phone: phone + usize::from(stress && current.is_some()),
+                    mark: character,
+                });
+            } else if let (Some(token), true) = (
+                current.as_mut().filter(|_| prefix.marks.is_empty()),
+                self.suffixes.contains_key(&character),
+            ) {
+                token.marks.push((character, false));
+                token.text.push(character);
+            } else if self.prefixes.contains_key(&character) {
+                prefix.marks.push((character, true));
+                prefix.text.push(character);
+            } else if self.suffixes.contains_key(&character) {
+                return Err(ParseError::MisplacedMark {
+                    phone,
+                    mark: character,
+                });
+            } else {
+                return Err(ParseError::UnknownSymbol {
+                    phone: phone + usize::from(current.is_some()),
+                    symbol: character,
+                });
+            }
+        }
+        let phone = tokens.len();
+        if tied {
+            return Err(ParseError::MisplacedMark { phone, mark: ties[0] });
+        }
+        if let Some(&(mark, _)) = prefix.marks.first() {
+            return Err(ParseError::MisplacedMark {
+                phone: phone + usize::from(current.is_some()),
+                mark,
+            });
+        }
+        tokens.extend(current);
+        Ok(tokens)
+    }
+
+    fn parse_token(
+        &self,
+        index: usize,
+        token: Token,
+    ) -> Result<Phone, ParseError> {
+        let invalid = || ParseError::InvalidPhone {
+            phone: index,
+            text: token.text.nfc().collect(),
+        };
+        let candidates =
+            self.candidates.get(&token.letters).ok_or_else(invalid)?;
+        for diacritics in self.mark_readings(&token.marks) {
+            for candidate in candidates {
+                for phone in readings(candidate, &diacritics) {
+                    let rendered = phone.render_with([]);
+                    if rendered.nfd().eq(token.text.nfd()) {
+                        return Ok(phone);
+                    }
+                }
+            }
+        }
+        Err(invalid())
+    }
+
+    fn mark_readings(&self, marks: &[(char, bool)]) -> Vec<Vec<Diacritic>> {
+        let mut readings = vec![Vec::new()];
+        for &(mark, prefix) in marks {
+            let table = if prefix { &self.prefixes } else { &self.suffixes };
+            readings = readings
+                .into_iter()
+                .flat_map(|reading: Vec<Diacritic>| {
+                    table[&mark].iter().map(move |diacritic| {
+                        let mut reading = reading.clone();
+                        reading.push(*diacritic);
+                        reading
+                    })
+                })
+                .collect();
+        }
+        readings
+    }
+}
+
+fn is_suprasegmental(diacritic: Diacritic) -> bool {
+    matches!(
+        diacritic,
+        Diacritic::HalfLong
+            | Diacritic::Long
+            | Diacritic::Overlong
+            | Diacritic::ExtraHighTone
+            | Diacritic::HighTone
+            | Diacritic::MidTone
+            | Diacritic::LowTone
+            | Diacritic::ExtraLowTone
+            | Diacritic::RisingTone
+            | Diacritic::FallingTone
+            | Diacritic::HighRisingTone
+            | Diacritic::LowRisingTone
+            | Diacritic::HighFallingTone
+            | Diacritic::LowFallingTone
+            | Diacritic::PeakingTone
+            | Diacritic::DippingTone
+    )
+}
+
+fn take(diacritics: &mut Vec<Diacritic>, diacritic: Diacritic) -> bool {
+    match diacritics.iter().position(|found| *found == diacritic) {
+        Some(index) => {
+            diacritics.remove(index);
+            true
+        },
+        None => false,
+    }
+}
+
+fn readings(candidate: &Candidate, diacritics: &[Diacritic]) -> Vec<Phone> {
+    let mut rest = diacritics.to_vec();
+    if !candidate.diacritics.iter().all(|diacritic| take(&mut rest, *diacritic))
+    {
+        return Vec::new();
+    }
+    let mut phones = Vec::new();
+    let (voiced_letter, paired) = match candidate.reading {
+        Reading::Consonant { voiced_letter, exclusive, .. } => {
+            (voiced_letter, exclusive)
+        },
+        Reading::Vowel { .. } => (true, false),
+    };
+    let mut phonations = Phonation::ALL;
+    phonations.sort_by_key(|phonation| {
+        Reverse(phonation.diacritics(voiced_letter, paired).len())
+    });
+    for phonation in phonations {
+        let mut rest = rest.clone();
+        match candidate.reading {
+            Reading::Consonant {
+                place,
+                manner,
+                airstream,
+                voiced_letter,
+                exclusive,
+            } => {
+                if exclusive && phonation.voiced_letter() != voiced_letter {
+                    continue;
+                }
+                let phonation_diacritics =
+                    phonation.diacritics(voiced_letter, exclusive);
+                if !phonation_diacritics
+                    .iter()
+                    .all(|diacritic| take(&mut rest, *diacritic))
+                {
+                    continue;
+                }
+                let consonant = Consonant {
+                    place,
+                    manner,
+                    airstream,
+                    secondary: Secondaries::new(),
+                    phonation,
+                    cavity: match manner {
+                        Manner::Nasal => Cavity::Nasal,
+                        _ => Cavity::Oral,
+                    },
+                    syllabic: false,
+                };
+                let Some(consonant) = consonant_reading(consonant, rest) else {
+                    continue;
+                };
+                phones.push(Phone::Consonant(consonant));
+                if phonation_diacritics.contains(&Diacritic::Aspirated) {
+                    let mut consonant = consonant;
+                    consonant.secondary.insert(Secondary::Postaspirated);
+                    phones.push(Phone::Consonant(consonant));
+                }
+            },
+            Reading::Vowel { height, frontness, roundedness } => {
+                if !phonation
+                    .diacritics(true, false)
+                    .iter()
+                    .all(|diacritic| take(&mut rest, *diacritic))
+                {
+                    continue;
+                }
+                let mut vowel = Vowel {
+                    height,
+                    frontness,
+                    roundedness,
+                    phonation,
+                    cavity: Cavity::Oral,
+                    syllabic: true,
+                };
+                if take(&mut rest, Diacritic::Nasalized) {
+                    vowel.cavity = Cavity::Nasal;
+                }
+                if take(&mut rest, Diacritic::NonSyllabic) {
+                    vowel.syllabic = false;
+                }
+                if rest.is_empty() {
+                    phones.push(Phone::Vowel(vowel));
+                }
+            },
+        }
+    }
+    phones
+}
+
+fn consonant_reading(
+    mut consonant: Consonant,
+    diacritics: Vec<Diacritic>,
+) -> Option<Consonant> {
+    for diacritic in diacritics {
+        let secondary = Secondary::ALL
+            .into_iter()
+            .find(|secondary| secondary.diacritic() == diacritic);
+        match (diacritic, secondary) {
+            (Diacritic::Ejective, _)
+                if consonant.airstream == Airstream::Pulmonic =>
+            {
+                consonant.airstream = Airstream::Ejective;
+            },
+            (Diacritic::Nasalized, _) if consonant.cavity == Cavity::Oral => {
+                consonant.cavity = Cavity::Nasal;
+            },
+            (Diacritic::Denasal, _)
+                if consonant.manner == Manner::Nasal
+                    && consonant.cavity == Cavity::Nasal =>
+            {
+                consonant.cavity = Cavity::Oral;
+            },
+            (Diacritic::Syllabic, _) if !consonant.syllabic => {
+                consonant.syllabic = true;
+            },
+            (_, Some(secondary)) if consonant.secondary.insert(secondary) => (),
+            _ => return None,
+        }
+    }
+    Some(consonant)
+}
+
+#[cfg(test)]
+mod test {
+    use super::{ParseError, Parser};
+    use crate::{
+        consonant::{
+            Airstream,
+            Consonant,
+            Manner,
+            Place,
+            Secondaries,
+            Secondary,
+        },
+        vowel::{Frontness, Height, Roundedness, Vowel},
+        Cavity,
+        Phonation,
+        Phone,
+    };
+    use proptest::prelude::*;
+
+    fn arbitrary_consonant() -> impl Strategy<Value = Consonant> {
+        (
+            prop::sample::select(&Place::ALL[..]),
+            prop::sample::select(&Manner::ALL[..]),
+            prop::sample::select(&Airstream::ALL[..]),
+            prop::sample::subsequence(&Secondary::ALL[..], 0 ..= 3),
+            prop::sample::select(&Phonation::ALL[..]),
+            any::<bool>(),
+            any::<bool>(),
+        )
+            .prop_map(
+                |(
+                    place,
+                    manner,
+                    airstream,
+                    secondary,
+                    phonation,
+                    nasal,
+                    syllabic,
+                )| {
+                    Consonant {
+                        place,
+                        manner,
+                        airstream,
+                        // Post-aspiration on a voiceless phone is written
+                        // as aspirated phonation.
+                        secondary: secondary
+                            .into_iter()
+                            .filter(|secondary| {
+                                *secondary != Secondary::Postaspirated
+                                    || phonation.is_voiced()
+                            })
+                            .collect(),
+                        phonation,
+                        cavity: if nasal {
+                            Cavity::Nasal
+                        } else {
+                            Cavity::Oral
+                        },
+                        syllabic,
+                    }
+                },
+            )
+    }
+
+    fn arbitrary_vowel() -> impl Strategy<Value = Vowel> {
+        (
+            prop::sample::select(&Height::ALL[..]),
+            prop::sample::select(&Frontness::ALL[..]),
+            prop::sample::select(&Roundedness::ALL[..]),
+            prop::sample::select(&Phonation::ALL[..]),
+            any::<bool>(),
+            any::<bool>(),
+        )
+            .prop_map(
+                |(
+                    height,
+                    frontness,
+                    roundedness,
+                    phonation,
+                    nasal,
+                    syllabic,
+                )| {
+                    Vowel {
+                        height,
+                        frontness,
+                        roundedness,
+                        phonation,
+                        cavity: if nasal {
+                            Cavity::Nasal
+                        } else {
+                            Cavity::Oral
+                        },
+                        syllabic,
+                    }
+                },
+            )
+    }
+
+    fn arbitrary_phone() -> impl Strategy<Value = Phone> {
+        prop_oneof![
+            arbitrary_consonant().prop_map(Phone::Consonant),
+            arbitrary_vowel().prop_map(Phone::Vowel),
+        ]
+    }
+
+    #[test]
+    fn parse_emitted_phones() {
+        let parser = Parser::builtin();
+        assert_eq!(
+            parser.parse_phone("ʋ̥̩̃"),
+            Ok(Phone::Consonant(Consonant {
+                place: Place::Labiodental,
+                manner: Manner::Approximant,
+                airstream: Airstream::Pulmonic,
+                secondary: Secondaries::new(),
+                phonation: Phonation::Voiceless,
+                cavity: Cavity::Nasal,
+                syllabic: true,
+            }))
+        );
+        assert_eq!(
+            parser.parse_phone("\u{1ebd}\u{0325}\u{032f}\u{02d5}"),
+            Ok(Phone::Vowel(Vowel {
+                height: Height::Mid,
+                frontness: Frontness::Front,
+                roundedness: Roundedness::Unrounded,
+                phonation: Phonation::Voiceless,
+                cavity: Cavity::Nasal,
+                syllabic: false,
+            }))
+        );
+    }
+
+    #[test]
+    fn parse_transcription() {
+        let phones = crate::parse("ⁿdʒ t͡ʃʰa kʷ pʼ").unwrap();
+        let rendered: Vec<_> =
+            phones.iter().map(|phone| phone.to_string()).collect();
+        assert_eq!(rendered, ["ⁿd", "ʒ", "t\u{0361}ʃʰ", "a", "kʷ", "pʼ"]);
+        assert!(matches!(
+            phones[2],
+            Phone::Consonant(Consonant {
+                manner: Manner::SibilantAffricate,
+                phonation: Phonation::Aspirated,
+                ..
+            })
+        ));
+        assert_eq!(
+            "ǂ".parse::<Phone>().map(|phone| phone.to_string()),
+            Ok(String::from("ǂ"))
+        );
+    }
+
+    #[test]
+    fn parse_errors() {
+        assert_eq!(
+            crate::parse("pa#"),
+            Err(ParseError::UnknownSymbol { phone: 2, symbol: '#' })
+        );
+        assert_eq!(
+            crate::parse("\u{0325}a"),
+            Err(ParseError::MisplacedMark { phone: 0, mark: '\u{0325}' })
+        );
+        assert_eq!(
+            crate::parse("t\u{0361}"),
+            Err(ParseError::MisplacedMark { phone: 0, mark: '\u{0361}' })
+        );
+        assert_eq!(
+            crate::parse("aʼ"),
+            Err(ParseError::InvalidPhone {
+                phone: 0,
+                text: String::from("aʼ")
+            })
+        );
+        assert_eq!(
+            crate::parse("n\u{030a}"),
+            Err(ParseError::InvalidPhone {
+                phone: 0,
+                text: String::from("n\u{030a}")
+            })
+        );
+        assert_eq!(
+            "pa".parse::<Phone>(),
+            Err(ParseError::NotSinglePhone { count: 2 })
+        );
+    }
+
+    #[test]
+    fn parse_suprasegmentals() {
+        assert_eq!(
+            crate::parse("aː"),
+            Err(ParseError::Suprasegmental { phone: 0, mark: 'ː' })
+        );
+        assert_eq!(
+            crate::parse("ˈpa"),
+            Err(ParseError::Suprasegmental { phone: 0, mark: 'ˈ' })
+        );
+        assert_eq!(
+            crate::parse("paˌta"),
+            Err(ParseError::Suprasegmental { phone: 2, mark: 'ˌ' })
+        );
+        assert_eq!(
+            crate::parse("ma˥˩"),
+            Err(ParseError::Suprasegmental { phone: 1, mark: '˥' })
+        );
+        assert_eq!(
+            crate::parse("ma\u{0302}"),
+            Err(ParseError::Suprasegmental { phone: 1, mark: '\u{0302}' })
+        );
+    }
+
+    fn unmodified_phones() -> Vec<Phone> {
+        let mut phones = Vec::new();
+        for phonation in Phonation::ALL {
+            for cavity in [Cavity::Oral, Cavity::Nasal] {
+                for syllabic in [false, true] {
+                    for place in Place::ALL {
+                        for manner in Manner::ALL {
+                            for airstream in Airstream::ALL {
+                                phones.push(Phone::Consonant(Consonant {
+                                    place,
+                                    manner,
+                                    airstream,
+                                    secondary: Secondaries::new(),
+                                    phonation,
+                                    cavity,
+                                    syllabic,
+                                }));
+                            }
+                        }
+                    }
+                    for height in Height::ALL {
+                        for frontness in Frontness::ALL {
+                            for roundedness in Roundedness::ALL {
+                                phones.push(Phone::Vowel(Vowel {
+                                    height,
+                                    frontness,
+                                    roundedness,
+                                    phonation,
+                                    cavity,
+                                    syllabic,
+                                }));
+                            }
+                        }
+                    }
+                }
+            }
+        }
+        phones
+    }
+
+    #[test]
+    fn round_trip_exhaustive() {
+        let parser = Parser::builtin();
+        for phone in unmodified_phones() {
+            let rendered = phone.try_render().unwrap();
+            assert_eq!(
+                parser.parse_phone(&rendered),
+                Ok(phone),
+                "{}",
+                rendered
+            );
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn round_trip(phone in arbitrary_phone()) {
+            let cluster = phone.try_render().unwrap();
+            prop_assert_eq!(Parser::builtin().parse_phone(&cluster), Ok(phone));
+        }
+
+        #[test]
+        fn round_trip_sequences(
+            phones in prop::collection::vec(arbitrary_phone(), 1 ..= 5),
+        ) {
+            let rendered: Vec<_> = phones
+                .iter()
+                .map(|phone| phone.try_render().unwrap())
+                .collect();
+            let text = rendered.join(" ");
+            let parsed = Parser::builtin().parse(&text).unwrap();
+            prop_assert_eq!(parsed, phones);
+        }
+    }
+}
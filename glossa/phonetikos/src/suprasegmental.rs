@@ -0,0 +1,423 @@
+use crate::{diacritic::Diacritic, Phone};
+use std::fmt;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub enum Length {
+    ExtraShort,
+    #[default]
+    Short,
+    HalfLong,
+    Long,
+    Overlong,
+}
+
+impl Length {
+    fn diacritic(self) -> Option<Diacritic> {
+        match self {
+            Self::ExtraShort => Some(Diacritic::ExtraShort),
+            Self::Short => None,
+            Self::HalfLong => Some(Diacritic::HalfLong),
+            Self::Long => Some(Diacritic::Long),
+            Self::Overlong => Some(Diacritic::Overlong),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub enum Stress {
+    #[default]
+    Unstressed,
+    Secondary,
+    Primary,
+}
+
+impl Stress {
+    pub(crate) fn mark(self) -> &'static str {
+        match self {
+            Self::Unstressed => "",
+            Self::Secondary => "ˌ",
+            Self::Primary => "ˈ",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Level {
+    ExtraLow,
+    Low,
+    Mid,
+    High,
+    ExtraHigh,
+}
+
+impl Level {
+    pub const ALL: [Self; 5] =
+        [Self::ExtraLow, Self::Low, Self::Mid, Self::High, Self::ExtraHigh];
+
+    pub fn number(self) -> u8 {
+        self as u8 + 1
+    }
+
+    pub fn from_number(number: u8) -> Option<Self> {
+        Self::ALL.get(usize::from(number.checked_sub(1)?)).copied()
+    }
+
+    pub fn letter(self) -> char {
+        match self {
+            Self::ExtraLow => '˩',
+            Self::Low => '˨',
+            Self::Mid => '˧',
+            Self::High => '˦',
+            Self::ExtraHigh => '˥',
+        }
+    }
+
+    fn diacritic(self) -> Diacritic {
+        match self {
+            Self::ExtraLow => Diacritic::ExtraLowTone,
+            Self::Low => Diacritic::LowTone,
+            Self::Mid => Diacritic::MidTone,
+            Self::High => Diacritic::HighTone,
+            Self::ExtraHigh => Diacritic::ExtraHighTone,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Tone {
+    Level(Level),
+    Contour(Level, Level),
+    Complex(Level, Level, Level),
+}
+
+impl Tone {
+    pub fn from_numbers(numbers: &[u8]) -> Option<Self> {
+        let levels = numbers
+            .iter()
+            .map(|number| Level::from_number(*number))
+            .collect::<Option<Vec<_>>>()?;
+        match levels[..] {
+            [level] => Some(Self::Level(level)),
+            [start, end] => Some(Self::Contour(start, end)),
+            [start, middle, end] => Some(Self::Complex(start, middle, end)),
+            _ => None,
+        }
+    }
+
+    pub fn levels(self) -> Vec<Level> {
+        match self {
+            Self::Level(level) => vec![level],
+            Self::Contour(start, end) => vec![start, end],
+            Self::Complex(start, middle, end) => vec![start, middle, end],
+        }
+    }
+
+    pub fn numbers(self) -> Vec<u8> {
+        self.levels().into_iter().map(Level::number).collect()
+    }
+
+    pub fn letters(self) -> String {
+        self.levels().into_iter().map(Level::letter).collect()
+    }
+
+    fn diacritic(self) -> Diacritic {
+        match self {
+            Self::Level(level) => level.diacritic(),
+            Self::Contour(start, end) if start == end => start.diacritic(),
+            Self::Contour(start, end) if start < end => {
+                if start >= Level::Mid {
+                    Diacritic::HighRisingTone
+                } else if end <= Level::Mid {
+                    Diacritic::LowRisingTone
+                } else {
+                    Diacritic::RisingTone
+                }
+            },
+            Self::Contour(start, end) => {
+                if end >= Level::Mid {
+                    Diacritic::HighFallingTone
+                } else if start <= Level::Mid {
+                    Diacritic::LowFallingTone
+                } else {
+                    Diacritic::FallingTone
+                }
+            },
+            Self::Complex(start, middle, end)
+                if middle > start && middle > end =>
+            {
+                Diacritic::PeakingTone
+            },
+            Self::Complex(start, middle, end)
+                if middle < start && middle < end =>
+            {
+                Diacritic::DippingTone
+            },
+            Self::Complex(start, _, end) => {
+                Self::Contour(start, end).diacritic()
+            },
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ToneNotation {
+    Diacritics,
+    Letters,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Suprasegmental {
+    pub length: Length,
+    pub tone: Option<Tone>,
+    pub stress: Stress,
+}
+
+impl Suprasegmental {
+    fn diacritics(self, notation: ToneNotation) -> Vec<Diacritic> {
+        let mut diacritics = Vec::new();
+        if notation == ToneNotation::Diacritics {
+            diacritics.extend(self.tone.map(Tone::diacritic));
+        }
+        diacritics.extend(self.length.diacritic());
+        diacritics
+    }
+
+    fn letters(self, notation: ToneNotation) -> String {
+        match (notation, self.tone) {
+            (ToneNotation::Letters, Some(tone)) => tone.letters(),
+            _ => String::new(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Segment {
+    pub phone: Phone,
+    pub suprasegmental: Suprasegmental,
+}
+
+impl Segment {
+    pub fn render(self, notation: ToneNotation) -> String {
+        let phone =
+            self.phone.render_with(self.suprasegmental.diacritics(notation));
+        format!(
+            "{}{}{}",
+            self.suprasegmental.stress.mark(),
+            phone,
+            self.suprasegmental.letters(notation)
+        )
+    }
+}
+
+impl fmt::Display for Segment {
+    fn fmt(&self, fmtr: &mut fmt::Formatter) -> fmt::Result {
+        fmtr.write_str(&self.render(ToneNotation::Diacritics))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Syllable {
+    pub phones: Vec<Phone>,
+    pub suprasegmental: Suprasegmental,
+}
+
+impl Syllable {
+    pub fn nucleus(&self) -> Option<usize> {
+        self.phones.iter().position(|phone| phone.syllabic()).or_else(|| {
+            self.phones
+                .iter()
+                .position(|phone| matches!(phone, Phone::Vowel(_)))
+        })
+    }
+
+    pub fn render(&self, notation: ToneNotation) -> String {
+        let nucleus = self.nucleus();
+        let mut rendered = String::from(self.suprasegmental.stress.mark());
+        for (index, phone) in self.phones.iter().enumerate() {
+            let diacritics = if Some(index) == nucleus {
+                self.suprasegmental.diacritics(notation)
+            } else {
+                Vec::new()
+            };
+            rendered.push_str(&phone.render_with(diacritics));
+        }
+        rendered.push_str(&self.suprasegmental.letters(notation));
+        rendered
+    }
+}
+
+impl fmt::Display for Syllable {
+    fn fmt(&self, fmtr: &mut fmt::Formatter) -> fmt::Result {
+        fmtr.write_str(&self.render(ToneNotation::Diacritics))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{
+        Length,
+        Level,
+        Segment,
+        Stress,
+        Suprasegmental,
+        Syllable,
+        Tone,
+        ToneNotation,
+    };
+    use crate::{
+        consonant::{
+            Airstream,
+            Consonant,
+            Manner,
+            Place,
+            Secondaries,
+            Secondary,
+        },
+        vowel::{Frontness, Height, Roundedness, Vowel},
+        Cavity,
+        Phonation,
+        Phone,
+    };
+
+    fn vowel_a() -> Phone {
+        Phone::Vowel(Vowel {
+            height: Height::Open,
+            frontness: Frontness::Front,
+            roundedness: Roundedness::Unrounded,
+            phonation: Phonation::Voiced,
+            cavity: Cavity::Oral,
+            syllabic: true,
+        })
+    }
+
+    fn consonant_m() -> Phone {
+        Phone::Consonant(Consonant {
+            place: Place::Bilabial,
+            manner: Manner::Nasal,
+            airstream: Airstream::Pulmonic,
+            secondary: Secondaries::default(),
+            phonation: Phonation::Voiced,
+            cavity: Cavity::Nasal,
+            syllabic: false,
+        })
+    }
+
+    #[test]
+    fn tone_numbers() {
+        assert_eq!(
+            Tone::from_numbers(&[2, 1, 4]),
+            Some(Tone::Complex(Level::Low, Level::ExtraLow, Level::High))
+        );
+        assert_eq!(Tone::from_numbers(&[6]), None);
+        assert_eq!(Tone::from_numbers(&[]), None);
+        assert_eq!(Tone::from_numbers(&[5, 1]).unwrap().numbers(), [5, 1]);
+        assert_eq!(Tone::from_numbers(&[5, 1]).unwrap().letters(), "˥˩");
+        assert_eq!(Tone::from_numbers(&[3, 5]).unwrap().letters(), "˧˥");
+    }
+
+    #[test]
+    fn tone_diacritics() {
+        let cases = [
+            (&[5][..], "a\u{030b}"),
+            (&[4], "a\u{0301}"),
+            (&[3], "a\u{0304}"),
+            (&[2], "a\u{0300}"),
+            (&[1], "a\u{030f}"),
+            (&[1, 5], "a\u{030c}"),
+            (&[5, 1], "a\u{0302}"),
+            (&[3, 5], "a\u{1dc4}"),
+            (&[1, 3], "a\u{1dc5}"),
+            (&[5, 3], "a\u{1dc7}"),
+            (&[3, 1], "a\u{1dc6}"),
+            (&[1, 5, 1], "a\u{1dc8}"),
+            (&[2, 1, 4], "a\u{1dc9}"),
+            (&[1, 3, 5], "a\u{030c}"),
+        ];
+        for (numbers, expected) in cases {
+            let segment = Segment {
+                phone: vowel_a(),
+                suprasegmental: Suprasegmental {
+                    tone: Tone::from_numbers(numbers),
+                    ..Suprasegmental::default()
+                },
+            };
+            assert_eq!(segment.to_string(), expected, "{:?}", numbers);
+        }
+    }
+
+    #[test]
+    fn segment_length_and_stress() {
+        let segment = |length, stress| Segment {
+            phone: vowel_a(),
+            suprasegmental: Suprasegmental { length, tone: None, stress },
+        };
+        assert_eq!(segment(Length::Short, Stress::Unstressed).to_string(), "a");
+        assert_eq!(segment(Length::Long, Stress::Primary).to_string(), "ˈaː");
+        assert_eq!(
+            segment(Length::HalfLong, Stress::Secondary).to_string(),
+            "ˌaˑ"
+        );
+        assert_eq!(
+            segment(Length::Overlong, Stress::Unstressed).to_string(),
+            "aːː"
+        );
+        assert_eq!(
+            segment(Length::ExtraShort, Stress::Unstressed).to_string(),
+            "a\u{0306}"
+        );
+    }
+
+    #[test]
+    fn segment_secondaries_and_length() {
+        let segment = Segment {
+            phone: Phone::Consonant(Consonant {
+                place: Place::Alveolar,
+                manner: Manner::Plosive,
+                airstream: Airstream::Ejective,
+                secondary: Secondaries::from([
+                    Secondary::Palatalized,
+                    Secondary::Postaspirated,
+                ]),
+                phonation: Phonation::Voiceless,
+                cavity: Cavity::Oral,
+                syllabic: false,
+            }),
+            suprasegmental: Suprasegmental {
+                length: Length::Overlong,
+                ..Suprasegmental::default()
+            },
+        };
+        assert_eq!(segment.to_string(), "tʼʲʰːː");
+    }
+
+    #[test]
+    fn syllable_tone_notations() {
+        let syllable = Syllable {
+            phones: vec![consonant_m(), vowel_a()],
+            suprasegmental: Suprasegmental {
+                length: Length::Long,
+                tone: Tone::from_numbers(&[5, 1]),
+                stress: Stress::Primary,
+            },
+        };
+        assert_eq!(syllable.nucleus(), Some(1));
+        assert_eq!(syllable.to_string(), "ˈma\u{0302}ː");
+        assert_eq!(syllable.render(ToneNotation::Letters), "ˈmaː˥˩");
+    }
+
+    #[test]
+    fn syllabic_consonant_nucleus() {
+        let mut nasal = consonant_m();
+        if let Phone::Consonant(consonant) = &mut nasal {
+            consonant.syllabic = true;
+        }
+        let syllable = Syllable {
+            phones: vec![nasal],
+            suprasegmental: Suprasegmental {
+                tone: Tone::from_numbers(&[2]),
+                ..Suprasegmental::default()
+            },
+        };
+        assert_eq!(syllable.to_string(), "m\u{0300}\u{0329}");
+    }
+}
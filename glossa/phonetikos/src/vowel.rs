@@ -1,27 +1,59 @@
-use crate::{diacritic::Diacritic, Cavity, Phonation};
-use diakritikos::{slot, GraphemeCluster};
+use crate::{diacritic::Diacritic, unsolved, Cavity, Phonation};
+use diakritikos::{slot, GraphemeCluster, SolveError};
 use std::fmt::{self, Debug};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub enum Height {
     Open,
+    NearOpen,
+    OpenMid,
     Mid,
+    CloseMid,
+    NearClose,
     Close,
 }
 
+impl Height {
+    pub const ALL: [Self; 7] = [
+        Self::Open,
+        Self::NearOpen,
+        Self::OpenMid,
+        Self::Mid,
+        Self::CloseMid,
+        Self::NearClose,
+        Self::Close,
+    ];
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub enum Frontness {
     Front,
+    NearFront,
     Central,
+    NearBack,
     Back,
 }
 
+impl Frontness {
+    pub const ALL: [Self; 5] = [
+        Self::Front,
+        Self::NearFront,
+        Self::Central,
+        Self::NearBack,
+        Self::Back,
+    ];
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub enum Roundedness {
     Unrounded,
     Rounded,
 }
 
+impl Roundedness {
+    pub const ALL: [Self; 2] = [Self::Unrounded, Self::Rounded];
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct Vowel {
     pub height: Height,
@@ -34,61 +66,120 @@ pub struct Vowel {
 
 impl Vowel {
     pub fn grapheme_cluster(self) -> GraphemeCluster<Diacritic> {
+        self.grapheme_cluster_with([])
+    }
+
+    pub(crate) fn grapheme_cluster_with<I>(
+        self,
+        extra: I,
+    ) -> GraphemeCluster<Diacritic>
+    where
+        I: IntoIterator<Item = Diacritic>,
+    {
+        let (character, diacritics) = self.marks(extra);
+        let hints = slot::hints(character).unwrap_or_default();
+        GraphemeCluster::solve(character, hints, diacritics.iter().copied())
+            .unwrap_or_else(|_| GraphemeCluster {
+                character,
+                slots: unsolved(diacritics),
+            })
+    }
+
+    pub fn try_grapheme_cluster(
+        self,
+    ) -> Result<GraphemeCluster<Diacritic>, SolveError> {
+        let (character, diacritics) = self.marks([]);
+        let hints = slot::hints(character).unwrap_or_default();
+        GraphemeCluster::solve(character, hints, diacritics)
+    }
+
+    fn marks<I>(self, extra: I) -> (char, Vec<Diacritic>)
+    where
+        I: IntoIterator<Item = Diacritic>,
+    {
         let mut diacritics = Vec::new();
-        let character = match (self.height, self.frontness, self.roundedness) {
-            (Height::Open, Frontness::Front, Roundedness::Unrounded) => 'a',
-            (Height::Open, Frontness::Front, Roundedness::Rounded) => 'ɶ',
-            (Height::Open, Frontness::Central, Roundedness::Unrounded) => {
-                diacritics.push(Diacritic::Centralized);
-                'a'
-            },
-            (Height::Open, Frontness::Central, Roundedness::Rounded) => {
-                diacritics.push(Diacritic::Centralized);
-                'ɶ'
-            },
-            (Height::Open, Frontness::Back, Roundedness::Unrounded) => 'ɑ',
-            (Height::Open, Frontness::Back, Roundedness::Rounded) => 'ɒ',
-            (Height::Mid, Frontness::Front, Roundedness::Unrounded) => {
-                diacritics.push(Diacritic::Lowered);
-                'e'
-            },
-            (Height::Mid, Frontness::Front, Roundedness::Rounded) => {
-                diacritics.push(Diacritic::Lowered);
-                'ø'
-            },
-            (Height::Mid, Frontness::Central, Roundedness::Unrounded) => 'ə',
-            (Height::Mid, Frontness::Central, Roundedness::Rounded) => {
-                diacritics.push(Diacritic::Labialized);
-                'ə'
-            },
-            (Height::Mid, Frontness::Back, Roundedness::Unrounded) => {
-                diacritics.push(Diacritic::Lowered);
-                'ɤ'
-            },
-            (Height::Mid, Frontness::Back, Roundedness::Rounded) => {
-                diacritics.push(Diacritic::Lowered);
-                'o'
-            },
-            (Height::Close, Frontness::Front, Roundedness::Unrounded) => 'i',
-            (Height::Close, Frontness::Front, Roundedness::Rounded) => 'y',
-            (Height::Close, Frontness::Central, Roundedness::Unrounded) => 'ɨ',
-            (Height::Close, Frontness::Central, Roundedness::Rounded) => 'ʉ',
-            (Height::Close, Frontness::Back, Roundedness::Unrounded) => 'ɯ',
-            (Height::Close, Frontness::Back, Roundedness::Rounded) => 'u',
-        };
+        let character = letter(
+            self.height, self.frontness, self.roundedness, &mut diacritics,
+        );
         match self.cavity {
             Cavity::Oral => (),
             Cavity::Nasal => diacritics.push(Diacritic::Nasalized),
         }
-        match self.phonation {
-            Phonation::Voiced => (),
-            Phonation::Voiceless => diacritics.push(Diacritic::Voiceless),
-        }
+        diacritics.extend(self.phonation.diacritics(true, false));
         if !self.syllabic {
             diacritics.push(Diacritic::NonSyllabic);
         }
-        let hints = slot::hints(character).unwrap();
-        GraphemeCluster::solve(character, hints, diacritics).unwrap()
+        diacritics.extend(extra);
+        (character, diacritics)
+    }
+}
+
+pub(crate) fn letter(
+    height: Height,
+    frontness: Frontness,
+    roundedness: Roundedness,
+    diacritics: &mut Vec<Diacritic>,
+) -> char {
+    if let Some(character) = native_letter(height, frontness, roundedness) {
+        return character;
+    }
+    let (height, frontness) = match (height, frontness) {
+        (_, Frontness::NearFront) => {
+            diacritics.push(Diacritic::Retracted);
+            (height, Frontness::Front)
+        },
+        (_, Frontness::NearBack) => {
+            diacritics.push(Diacritic::Advanced);
+            (height, Frontness::Back)
+        },
+        (Height::NearClose, _) => {
+            diacritics.push(Diacritic::Lowered);
+            (Height::Close, frontness)
+        },
+        (Height::Mid, _) => {
+            diacritics.push(Diacritic::Lowered);
+            (Height::CloseMid, frontness)
+        },
+        (Height::NearOpen, _) => {
+            diacritics.push(Diacritic::Lowered);
+            (Height::OpenMid, frontness)
+        },
+        (Height::Open, Frontness::Central) => {
+            diacritics.push(Diacritic::Centralized);
+            (Height::Open, Frontness::Front)
+        },
+        _ => unreachable!("every cardinal vowel has a letter"),
+    };
+    letter(height, frontness, roundedness, diacritics)
+}
+
+fn native_letter(
+    height: Height,
+    frontness: Frontness,
+    roundedness: Roundedness,
+) -> Option<char> {
+    let (unrounded, rounded) = match (height, frontness) {
+        (Height::Close, Frontness::Front) => (Some('i'), Some('y')),
+        (Height::Close, Frontness::Central) => (Some('ɨ'), Some('ʉ')),
+        (Height::Close, Frontness::Back) => (Some('ɯ'), Some('u')),
+        (Height::NearClose, Frontness::NearFront) => (Some('ɪ'), Some('ʏ')),
+        (Height::NearClose, Frontness::NearBack) => (None, Some('ʊ')),
+        (Height::CloseMid, Frontness::Front) => (Some('e'), Some('ø')),
+        (Height::CloseMid, Frontness::Central) => (Some('ɘ'), Some('ɵ')),
+        (Height::CloseMid, Frontness::Back) => (Some('ɤ'), Some('o')),
+        (Height::Mid, Frontness::Central) => (Some('ə'), None),
+        (Height::OpenMid, Frontness::Front) => (Some('ɛ'), Some('œ')),
+        (Height::OpenMid, Frontness::Central) => (Some('ɜ'), Some('ɞ')),
+        (Height::OpenMid, Frontness::Back) => (Some('ʌ'), Some('ɔ')),
+        (Height::NearOpen, Frontness::Front) => (Some('æ'), None),
+        (Height::NearOpen, Frontness::Central) => (Some('ɐ'), None),
+        (Height::Open, Frontness::Front) => (Some('a'), Some('ɶ')),
+        (Height::Open, Frontness::Back) => (Some('ɑ'), Some('ɒ')),
+        _ => (None, None),
+    };
+    match roundedness {
+        Roundedness::Unrounded => unrounded,
+        Roundedness::Rounded => rounded,
     }
 }
 
@@ -114,4 +205,89 @@ mod test {
         };
         assert_eq!(vowel.to_string(), "ẽ̥̯˕");
     }
+
+    #[test]
+    fn breathy_and_creaky_vowels() {
+        let vowel = |phonation| Vowel {
+            roundedness: Roundedness::Unrounded,
+            height: Height::Open,
+            frontness: Frontness::Front,
+            cavity: Cavity::Oral,
+            phonation,
+            syllabic: true,
+        };
+        assert_eq!(vowel(Phonation::Breathy).to_string(), "a\u{0324}");
+        assert_eq!(vowel(Phonation::Creaky).to_string(), "a\u{0330}");
+    }
+
+    #[test]
+    fn vowel_space() {
+        let vowel = |height, frontness, roundedness| Vowel {
+            roundedness,
+            height,
+            frontness,
+            cavity: Cavity::Oral,
+            phonation: Phonation::Voiced,
+            syllabic: true,
+        };
+        let cases = [
+            (Height::Close, Frontness::Back, Roundedness::Rounded, "u"),
+            (
+                Height::NearClose,
+                Frontness::NearFront,
+                Roundedness::Unrounded,
+                "ɪ",
+            ),
+            (Height::NearClose, Frontness::NearBack, Roundedness::Rounded, "ʊ"),
+            (
+                Height::NearClose,
+                Frontness::Front,
+                Roundedness::Unrounded,
+                "i\u{031e}",
+            ),
+            (Height::CloseMid, Frontness::Central, Roundedness::Rounded, "ɵ"),
+            (Height::Mid, Frontness::Central, Roundedness::Unrounded, "ə"),
+            (Height::Mid, Frontness::Back, Roundedness::Rounded, "o\u{031e}"),
+            (Height::OpenMid, Frontness::Front, Roundedness::Unrounded, "ɛ"),
+            (Height::OpenMid, Frontness::Central, Roundedness::Unrounded, "ɜ"),
+            (Height::OpenMid, Frontness::Central, Roundedness::Rounded, "ɞ"),
+            (Height::OpenMid, Frontness::Back, Roundedness::Unrounded, "ʌ"),
+            (Height::NearOpen, Frontness::Front, Roundedness::Unrounded, "æ"),
+            (Height::NearOpen, Frontness::Central, Roundedness::Unrounded, "ɐ"),
+            (
+                Height::NearOpen,
+                Frontness::Back,
+                Roundedness::Rounded,
+                "ɔ\u{031e}",
+            ),
+            (
+                Height::Open,
+                Frontness::Central,
+                Roundedness::Unrounded,
+                "a\u{0308}",
+            ),
+            (
+                Height::CloseMid,
+                Frontness::NearFront,
+                Roundedness::Unrounded,
+                "e\u{0320}",
+            ),
+            (
+                Height::OpenMid,
+                Frontness::NearBack,
+                Roundedness::Rounded,
+                "ɔ\u{031f}",
+            ),
+        ];
+        for (height, frontness, roundedness, expected) in cases {
+            assert_eq!(
+                vowel(height, frontness, roundedness).to_string(),
+                expected,
+                "{:?} {:?} {:?}",
+                height,
+                frontness,
+                roundedness
+            );
+        }
+    }
 }